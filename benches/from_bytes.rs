@@ -0,0 +1,46 @@
+//! Measures the duration of detections from byte slices, compared to detections from readers.
+//!
+//! Run with `cargo bench --bench from_bytes`.
+
+use file_format::FileFormat;
+use std::{io::Cursor, time::Instant};
+
+fn main() {
+    const ITERATIONS: u32 = 100_000;
+    const SAMPLES: [&[u8]; 4] = [
+        b"\x89PNG\r\n\x1A\n\0\0\0\x0DIHDR",
+        b"%PDF-1.7\n",
+        b"\x28\xB5\x2F\xFD\0\0\0\0",
+        b"\x12\x34\x56\x78\x9A\xBC\xDE\xF0",
+    ];
+
+    let start = Instant::now();
+    let mut checksum = 0u64;
+    for _ in 0..ITERATIONS {
+        for sample in SAMPLES {
+            checksum += FileFormat::from_bytes(sample).id() as u64;
+        }
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "from_bytes: {:?} per detection (checksum: {})",
+        elapsed / (ITERATIONS * SAMPLES.len() as u32),
+        checksum
+    );
+
+    let start = Instant::now();
+    let mut checksum = 0u64;
+    for _ in 0..ITERATIONS {
+        for sample in SAMPLES {
+            checksum += FileFormat::from_reader(Cursor::new(sample))
+                .unwrap_or_default()
+                .id() as u64;
+        }
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "from_reader: {:?} per detection (checksum: {})",
+        elapsed / (ITERATIONS * SAMPLES.len() as u32),
+        checksum
+    );
+}
@@ -0,0 +1,30 @@
+//! Measures the duration of extension lookups.
+//!
+//! Run with `cargo bench --bench from_extension`.
+
+use file_format::FileFormat;
+use std::time::Instant;
+
+fn main() {
+    const ITERATIONS: u32 = 1_000_000;
+    const EXTENSIONS: [&str; 6] = ["pdf", "jpg", "mp4", "exe", "zst", "unknown"];
+
+    // Builds the lookup table beforehand.
+    FileFormat::from_extension("");
+
+    let start = Instant::now();
+    let mut checksum = 0u64;
+    for _ in 0..ITERATIONS {
+        for extension in EXTENSIONS {
+            checksum +=
+                FileFormat::from_extension(extension).map_or(0, |format| format.id()) as u64;
+        }
+    }
+    let elapsed = start.elapsed();
+
+    println!(
+        "from_extension: {:?} per lookup (checksum: {})",
+        elapsed / (ITERATIONS * EXTENSIONS.len() as u32),
+        checksum
+    );
+}
@@ -0,0 +1,11 @@
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE users (
+  id INTEGER PRIMARY KEY,
+  name TEXT NOT NULL,
+  email TEXT
+);
+INSERT INTO users VALUES(1,'Alice','alice@example.com');
+INSERT INTO users VALUES(2,'Bob; the builder','bob@example.com');
+CREATE INDEX users_name ON users (name);
+COMMIT;
@@ -0,0 +1,27 @@
+--
+-- PostgreSQL database dump
+--
+
+SET statement_timeout = 0;
+SET client_encoding = 'UTF8';
+SET standard_conforming_strings = on;
+SELECT pg_catalog.set_config('search_path', '', false);
+
+CREATE TABLE public.notes (
+    id integer NOT NULL,
+    body text
+);
+
+ALTER TABLE public.notes OWNER TO postgres;
+
+COPY public.notes (id, body) FROM stdin;
+1	Don't forget; it's due today
+2	"Quoted" text -- not a comment
+\.
+
+ALTER TABLE ONLY public.notes
+    ADD CONSTRAINT notes_pkey PRIMARY KEY (id);
+
+--
+-- PostgreSQL database dump complete
+--
@@ -0,0 +1,57 @@
+//! C bindings of the core detection functions.
+//!
+//! File formats are represented by their [stable identifier](`FileFormat::id`). The functions
+//! are exported unmangled, so a C header can be generated with `cbindgen` and a C library built
+//! with `cargo rustc --release --features ffi --crate-type cdylib` (or `staticlib`).
+//!
+//! # Memory ownership
+//!
+//! The strings returned by [`file_format_name`], [`file_format_media_type`] and
+//! [`file_format_extension`] are NUL-terminated, statically allocated and valid for the whole
+//! lifetime of the program. They are owned by the library and must never be freed or modified by
+//! the caller.
+
+use crate::FileFormat;
+use std::{os::raw::c_char, ptr, slice};
+
+/// Determines the file format of a buffer and returns its stable identifier.
+///
+/// A null pointer or a zero length is handled as an empty buffer.
+///
+/// # Safety
+///
+/// If not null, `ptr` must be valid for reads of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn file_format_from_bytes(ptr: *const u8, len: usize) -> u32 {
+    let bytes = if ptr.is_null() || len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(ptr, len)
+    };
+    FileFormat::from_bytes(bytes).id()
+}
+
+/// Returns the full name of the file format having the given identifier, or a null pointer if
+/// the identifier is unknown.
+#[no_mangle]
+pub extern "C" fn file_format_name(id: u32) -> *const c_char {
+    FileFormat::from_id(id).map_or(ptr::null(), |format| format.name_with_nul().as_ptr().cast())
+}
+
+/// Returns the media type of the file format having the given identifier, or a null pointer if
+/// the identifier is unknown.
+#[no_mangle]
+pub extern "C" fn file_format_media_type(id: u32) -> *const c_char {
+    FileFormat::from_id(id).map_or(ptr::null(), |format| {
+        format.media_type_with_nul().as_ptr().cast()
+    })
+}
+
+/// Returns the extension of the file format having the given identifier, or a null pointer if
+/// the identifier is unknown.
+#[no_mangle]
+pub extern "C" fn file_format_extension(id: u32) -> *const c_char {
+    FileFormat::from_id(id).map_or(ptr::null(), |format| {
+        format.extension_with_nul().as_ptr().cast()
+    })
+}
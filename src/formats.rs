@@ -7,6 +7,7 @@ formats! {
     media_type = "application/x-abiword"
     extension = "abw"
     kind = Document
+    feature = "reader-xml"
 
     format = AbiwordTemplate
     name = "AbiWord Template"
@@ -14,6 +15,15 @@ formats! {
     media_type = "application/x-abiword-template"
     extension = "awt"
     kind = Document
+    feature = "reader-xml"
+
+    format = AbstractSyntaxNotationOne
+    name = "Abstract Syntax Notation One"
+    short_name = "ASN.1"
+    media_type = "application/x-asn1"
+    extension = "der"
+    kind = Application
+    feature = "reader-asn1"
 
     format = ActionsMediaVideo
     name = "Actions Media Video"
@@ -49,6 +59,7 @@ formats! {
     media_type = "application/x-amf"
     extension = "amf"
     kind = Model
+    feature = "reader-xml"
 
     format = AdobeFlashPlayerAudio
     name = "Adobe Flash Player Audio"
@@ -78,12 +89,21 @@ formats! {
     extension = "f4v"
     kind = Video
 
+    format = AdobeFontMetrics
+    name = "Adobe Font Metrics"
+    short_name = "AFM"
+    media_type = "application/x-font-afm"
+    extension = "afm"
+    kind = Font
+    feature = "reader-txt"
+
     format = AdobeIllustratorArtwork
     name = "Adobe Illustrator Artwork"
     short_name = "AI"
     media_type = "application/vnd.adobe.illustrator"
     extension = "ai"
     kind = Image
+    feature = "reader-pdf"
 
     format = AdobeIndesignDocument
     name = "Adobe InDesign Document"
@@ -98,6 +118,7 @@ formats! {
     media_type = "application/vnd.adobe.air-application-installer-package+zip"
     extension = "air"
     kind = Package
+    feature = "reader-zip"
 
     format = AdobePhotoshopDocument
     name = "Adobe Photoshop Document"
@@ -126,6 +147,7 @@ formats! {
     media_type = "video/x-ms-asx"
     extension = "asx"
     kind = Playlist
+    feature = "reader-xml"
 
     format = AdvancedSystemsFormat
     name = "Advanced Systems Format"
@@ -167,6 +189,7 @@ formats! {
     media_type = "application/vnd.android.package-archive"
     extension = "apk"
     kind = Package
+    feature = "reader-zip"
 
     format = AnimatedPortableNetworkGraphics
     name = "Animated Portable Network Graphics"
@@ -175,6 +198,7 @@ formats! {
     extension = "apng"
     kind = Image
 
+    /// Feather V2 files are also detected as Apache Arrow Columnar, since they share the layout.
     format = ApacheArrowColumnar
     name = "Apache Arrow Columnar"
     short_name = "Arrow"
@@ -182,6 +206,13 @@ formats! {
     extension = "arrow"
     kind = Application
 
+    format = ApacheArrowStream
+    name = "Apache Arrow Stream"
+    media_type = "application/vnd.apache.arrow.stream"
+    extension = "arrows"
+    kind = Application
+    feature = "reader-arrow"
+
     format = ApacheAvroObjectContainer
     name = "Apache Avro Object Container"
     short_name = "Avro"
@@ -196,6 +227,12 @@ formats! {
     extension = "parquet"
     kind = Application
 
+    format = Appimage
+    name = "AppImage"
+    media_type = "application/vnd.appimage"
+    extension = "appimage"
+    kind = Executable
+
     format = AppleDiskImage
     name = "Apple Disk Image"
     short_name = "DMG"
@@ -250,6 +287,7 @@ formats! {
     short_name = "BIN"
     media_type = "application/octet-stream"
     extension = "bin"
+    preferred = true
     kind = Application
 
     format = ArchivedByRobertJung
@@ -271,6 +309,7 @@ formats! {
     media_type = "application/atom+xml"
     extension = "atom"
     kind = Syndication
+    feature = "reader-xml"
 
     format = Au
     name = "Au"
@@ -292,6 +331,13 @@ formats! {
     extension = "aiff"
     kind = Audio
 
+    format = AudioInterchangeFileFormatCompressed
+    name = "Audio Interchange File Format Compressed"
+    short_name = "AIFC"
+    media_type = "audio/x-aifc"
+    extension = "aifc"
+    kind = Audio
+
     format = AudioVideoInterleave
     name = "Audio Video Interleave"
     short_name = "AVI"
@@ -319,6 +365,7 @@ formats! {
     media_type = "model/x-123dx"
     extension = "123dx"
     kind = Model
+    feature = "reader-zip"
 
     format = AutodeskAlias
     name = "Autodesk Alias"
@@ -347,6 +394,7 @@ formats! {
     media_type = "model/x-iam"
     extension = "iam"
     kind = Model
+    feature = "reader-cfb"
 
     format = AutodeskInventorDrawing
     name = "Autodesk Inventor Drawing"
@@ -354,6 +402,7 @@ formats! {
     media_type = "model/x-idw"
     extension = "idw"
     kind = Model
+    feature = "reader-cfb"
 
     format = AutodeskInventorPart
     name = "Autodesk Inventor Part"
@@ -361,6 +410,7 @@ formats! {
     media_type = "model/x-ipt"
     extension = "ipt"
     kind = Model
+    feature = "reader-cfb"
 
     format = AutodeskInventorPresentation
     name = "Autodesk Inventor Presentation"
@@ -368,6 +418,7 @@ formats! {
     media_type = "model/x-ipn"
     extension = "ipn"
     kind = Model
+    feature = "reader-cfb"
 
     format = Av1ImageFileFormat
     name = "AV1 Image File Format"
@@ -383,6 +434,13 @@ formats! {
     extension = "avifs"
     kind = Image
 
+    format = Base64
+    name = "Base64"
+    media_type = "application/base64"
+    extension = "b64"
+    kind = Text
+    feature = "reader-txt"
+
     format = BdavMpeg2TransportStream
     name = "BDAV MPEG-2 Transport Stream"
     short_name = "M2TS"
@@ -397,6 +455,14 @@ formats! {
     extension = "bpg"
     kind = Image
 
+    format = BinaryJson
+    name = "Binary JSON"
+    short_name = "BSON"
+    media_type = "application/bson"
+    extension = "bson"
+    kind = Application
+    feature = "reader-bson"
+
     format = BitmapFontAscii
     name = "Bitmap Font ASCII"
     short_name = "FNT"
@@ -458,6 +524,7 @@ formats! {
     short_name = "CAB"
     media_type = "application/vnd.ms-cab-compressed"
     extension = "cab"
+    preferred = true
     kind = Archive
 
     format = CanonRaw
@@ -481,6 +548,13 @@ formats! {
     extension = "cr3"
     kind = Image
 
+    format = CapnProto
+    name = "Cap'n Proto"
+    media_type = "application/x-capnp"
+    extension = "bin"
+    kind = Application
+    feature = "reader-capnp"
+
     format = CdAudio
     name = "CD Audio"
     short_name = "CDA"
@@ -508,6 +582,7 @@ formats! {
     media_type = "application/vnd.circuitdiagram.document.main+xml"
     extension = "cddx"
     kind = Document
+    feature = "reader-zip"
 
     format = ClojureScript
     name = "Clojure Script"
@@ -515,6 +590,13 @@ formats! {
     extension = "clj"
     kind = Text
 
+    format = ClonecdControl
+    name = "CloneCD Control"
+    short_name = "CCD"
+    media_type = "application/x-clonecd-control"
+    extension = "ccd"
+    kind = Disk
+
     format = CommonObjectFileFormat
     name = "Common Object File Format"
     short_name = "COFF"
@@ -529,6 +611,58 @@ formats! {
     extension = "cfb"
     kind = Application
 
+    format = CompressedHunksOfData
+    name = "Compressed Hunks of Data"
+    short_name = "CHD"
+    media_type = "application/x-mame-chd"
+    extension = "chd"
+    kind = Disk
+
+    format = CompressedIso
+    name = "Compressed ISO"
+    short_name = "CSO"
+    media_type = "application/x-compressed-iso"
+    extension = "cso"
+    preferred = true
+    kind = Rom
+
+    format = CompressedTape
+    name = "Compressed Tape Archive"
+    short_name = "TGZ"
+    media_type = "application/x-compressed-tar"
+    extension = "tgz"
+    kind = Archive
+    feature = "decompress-gzip"
+
+    format = CondaPackage
+    name = "Conda Package"
+    short_name = "CONDA"
+    media_type = "application/x-conda"
+    extension = "conda"
+    kind = Package
+    feature = "reader-zip"
+
+    format = ContextDiff
+    name = "Context Diff"
+    media_type = "text/x-diff"
+    extension = "diff"
+    kind = Text
+    feature = "reader-txt"
+
+    format = CoreAudioFormat
+    name = "Core Audio Format"
+    short_name = "CAF"
+    media_type = "audio/x-caf"
+    extension = "caf"
+    kind = Audio
+
+    format = Coreldraw
+    name = "CorelDRAW"
+    short_name = "CDR"
+    media_type = "application/vnd.corel-draw"
+    extension = "cdr"
+    kind = Image
+
     format = Cpio
     name = "cpio"
     media_type = "application/x-cpio"
@@ -561,6 +695,7 @@ formats! {
     short_name = "DER"
     media_type = "application/x-x509-ca-cert"
     extension = "der"
+    preferred = true
     kind = Certificate
 
     format = DesignWebFormat
@@ -576,6 +711,7 @@ formats! {
     media_type = "model/vnd.dwfx+xps"
     extension = "dwfx"
     kind = Model
+    feature = "reader-zip"
 
     format = DigitalAssetExchange
     name = "Digital Asset Exchange"
@@ -583,6 +719,7 @@ formats! {
     media_type = "model/vnd.collada+xml"
     extension = "dae"
     kind = Model
+    feature = "reader-xml"
 
     format = DigitalImagingAndCommunicationsInMedicine
     name = "Digital Imaging and Communications in Medicine"
@@ -598,12 +735,41 @@ formats! {
     extension = "dpx"
     kind = Image
 
+    format = DirectxIntermediateLanguage
+    name = "DirectX Intermediate Language"
+    short_name = "DXIL"
+    media_type = "application/x-dxil"
+    extension = "cso"
+    kind = Executable
+    feature = "reader-dxbc"
+
+    format = DirectxShaderBytecode
+    name = "DirectX Shader Bytecode"
+    short_name = "DXBC"
+    media_type = "application/x-dxbc"
+    extension = "cso"
+    kind = Executable
+
     format = Djvu
     name = "DjVu"
     media_type = "image/vnd.djvu"
     extension = "djvu"
     kind = Image
 
+    format = DockerImage
+    name = "Docker Image"
+    media_type = "application/x-docker-image"
+    extension = "tar"
+    kind = Archive
+    feature = "reader-tar"
+
+    format = DownloadableSounds
+    name = "Downloadable Sounds"
+    short_name = "DLS"
+    media_type = "audio/dls"
+    extension = "dls"
+    kind = Audio
+
     format = DrawingExchangeFormatAscii
     name = "Drawing Exchange Format ASCII"
     short_name = "DXF"
@@ -624,6 +790,13 @@ formats! {
     media_type = "application/vnd.jgraph.mxfile"
     extension = "drawio"
     kind = Document
+    feature = "reader-xml"
+
+    format = DuckdbDatabase
+    name = "DuckDB Database"
+    media_type = "application/x-duckdb"
+    extension = "duckdb"
+    kind = Database
 
     format = DynamicLinkLibrary
     name = "Dynamic Link Library"
@@ -631,6 +804,7 @@ formats! {
     media_type = "application/vnd.microsoft.portable-executable"
     extension = "dll"
     kind = Executable
+    feature = "reader-exe"
 
     format = ElectronicPublication
     name = "Electronic Publication"
@@ -638,6 +812,14 @@ formats! {
     media_type = "application/epub+zip"
     extension = "epub"
     kind = Book
+    feature = "reader-zip"
+
+    format = ElectronicSoftwareDownload
+    name = "Electronic Software Download"
+    short_name = "ESD"
+    media_type = "application/x-ms-esd"
+    extension = "esd"
+    kind = Archive
 
     format = EmbeddedOpentype
     name = "Embedded OpenType"
@@ -659,12 +841,35 @@ formats! {
     extension = "eps"
     kind = Application
 
+    format = EncryptedOfficeOpenXml
+    name = "Encrypted Office Open XML"
+    media_type = "application/encrypted"
+    extension = "docx"
+    kind = Document
+    feature = "reader-cfb"
+
+    format = EnhancedMetafile
+    name = "Enhanced Metafile"
+    short_name = "EMF"
+    media_type = "image/emf"
+    extension = "emf"
+    kind = Image
+
     format = EnterpriseApplicationArchive
     name = "Enterprise Application Archive"
     short_name = "EAR"
     media_type = "application/java-archive"
     extension = "ear"
     kind = Package
+    feature = "reader-zip"
+
+    format = EsriAsciiGrid
+    name = "Esri ASCII Grid"
+    short_name = "ASC"
+    media_type = "application/x-esri-ascii-grid"
+    extension = "asc"
+    kind = Geospatial
+    feature = "reader-txt"
 
     format = ExecutableAndLinkableFormat
     name = "Executable and Linkable Format"
@@ -686,6 +891,7 @@ formats! {
     media_type = "model/x3d+xml"
     extension = "x3d"
     kind = Model
+    feature = "reader-xml"
 
     format = ExtensibleArchive
     name = "Extensible Archive"
@@ -706,6 +912,7 @@ formats! {
     short_name = "XML"
     media_type = "text/xml"
     extension = "xml"
+    preferred = true
     kind = Text
 
     format = ExtensibleStylesheetLanguageTransformations
@@ -714,6 +921,7 @@ formats! {
     media_type = "application/xslt+xml"
     extension = "xsl"
     kind = Application
+    feature = "reader-xml"
 
     format = Farbfeld
     name = "farbfeld"
@@ -729,12 +937,19 @@ formats! {
     extension = "xm"
     kind = Audio
 
+    format = FeatherV1
+    name = "Feather V1"
+    media_type = "application/x-feather"
+    extension = "feather"
+    kind = Application
+
     format = Fictionbook
     name = "FictionBook"
     short_name = "FB2"
     media_type = "application/x-fb2+xml"
     extension = "fb2"
     kind = Book
+    feature = "reader-xml"
 
     format = FictionbookZipped
     name = "FictionBook Zipped"
@@ -742,6 +957,7 @@ formats! {
     media_type = "application/x-fbz"
     extension = "fbz"
     kind = Book
+    feature = "reader-zip"
 
     format = Filmbox
     name = "Filmbox"
@@ -757,6 +973,13 @@ formats! {
     extension = "flv"
     kind = Video
 
+    format = Flatbuffers
+    name = "FlatBuffers"
+    media_type = "application/x-flatbuffers"
+    extension = "bin"
+    kind = Application
+    feature = "reader-flatbuffers"
+
     format = FlexibleAndInteroperableDataTransfer
     name = "Flexible and Interoperable Data Transfer"
     short_name = "FIT"
@@ -798,6 +1021,7 @@ formats! {
     media_type = "model/x-f3d"
     extension = "f3d"
     kind = Model
+    feature = "reader-zip"
 
     format = GameBoyAdvanceRom
     name = "Game Boy Advance ROM"
@@ -827,12 +1051,20 @@ formats! {
     extension = "gg"
     kind = Rom
 
+    format = GamecubeDisc
+    name = "GameCube Disc"
+    short_name = "GCM"
+    media_type = "application/x-gamecube-rom"
+    extension = "gcm"
+    kind = Rom
+
     format = GeographyMarkupLanguage
     name = "Geography Markup Language"
     short_name = "GML"
     media_type = "application/gml+xml"
     extension = "gml"
     kind = Geospatial
+    feature = "reader-xml"
 
     format = GettextMachineObject
     name = "gettext Machine Object"
@@ -848,6 +1080,20 @@ formats! {
     extension = "glb"
     kind = Model
 
+    format = GlyphBitmapDistributionFormat
+    name = "Glyph Bitmap Distribution Format"
+    short_name = "BDF"
+    media_type = "application/x-font-bdf"
+    extension = "bdf"
+    kind = Font
+
+    format = GodotResourcePack
+    name = "Godot Resource Pack"
+    short_name = "PCK"
+    media_type = "application/x-godot-resource-pack"
+    extension = "pck"
+    kind = Package
+
     format = GoogleChromeExtension
     name = "Google Chrome Extension"
     short_name = "CRX"
@@ -868,6 +1114,7 @@ formats! {
     media_type = "application/gpx+xml"
     extension = "gpx"
     kind = Geospatial
+    feature = "reader-xml"
 
     format = GraphicsInterchangeFormat
     name = "Graphics Interchange Format"
@@ -876,6 +1123,14 @@ formats! {
     extension = "gif"
     kind = Image
 
+    format = GraphvizDot
+    name = "Graphviz DOT"
+    short_name = "DOT"
+    media_type = "text/vnd.graphviz"
+    extension = "gv"
+    kind = Text
+    feature = "reader-txt"
+
     format = Gzip
     name = "gzip"
     short_name = "GZ"
@@ -883,6 +1138,14 @@ formats! {
     extension = "gz"
     kind = Compression
 
+    format = HangulWordProcessor
+    name = "Hangul Word Processor"
+    short_name = "HWP"
+    media_type = "application/x-hwp"
+    extension = "hwp"
+    kind = Document
+    feature = "reader-cfb"
+
     format = HighEfficiencyImageCoding
     name = "High Efficiency Image Coding"
     short_name = "HEIC"
@@ -911,6 +1174,14 @@ formats! {
     extension = "heifs"
     kind = Image
 
+    format = HttpArchive
+    name = "HTTP Archive"
+    short_name = "HAR"
+    media_type = "application/har+json"
+    extension = "har"
+    kind = Application
+    feature = "reader-json"
+
     format = HypertextMarkupLanguage
     name = "HyperText Markup Language"
     short_name = "HTML"
@@ -939,6 +1210,14 @@ formats! {
     extension = "8svx"
     kind = Audio
 
+    format = IffAnimation
+    name = "IFF Animation"
+    short_name = "ANIM"
+    media_type = "video/x-anim"
+    extension = "anim"
+    kind = Video
+    feature = "reader-iff"
+
     format = ImpulseTrackerModule
     name = "Impulse Tracker Module"
     short_name = "IT"
@@ -952,6 +1231,7 @@ formats! {
     media_type = "application/vnd.adobe.indesign-idml-package"
     extension = "idml"
     kind = Document
+    feature = "reader-zip"
 
     format = InitialGraphicsExchangeSpecification
     name = "Initial Graphics Exchange Specification"
@@ -960,6 +1240,12 @@ formats! {
     extension = "iges"
     kind = Model
 
+    format = InstallshieldCabinet
+    name = "InstallShield Cabinet"
+    media_type = "application/x-installshield"
+    extension = "cab"
+    kind = Archive
+
     format = InterQuakeExport
     name = "Inter-Quake Export"
     short_name = "IQE"
@@ -974,12 +1260,29 @@ formats! {
     extension = "iqm"
     kind = Model
 
+    format = InterchangeFileFormat
+    name = "Interchange File Format"
+    short_name = "IFF"
+    media_type = "application/x-iff"
+    extension = "iff"
+    kind = Application
+    feature = "reader-iff"
+
+    format = InterleavedBitmap
+    name = "Interleaved Bitmap"
+    short_name = "ILBM"
+    media_type = "image/x-ilbm"
+    extension = "lbm"
+    kind = Image
+    feature = "reader-iff"
+
     format = IosAppStorePackage
     name = "iOS App Store Package"
     short_name = "IPA"
     media_type = "application/x-ios-app"
     extension = "ipa"
     kind = Package
+    feature = "reader-zip"
 
     format = Iso9660
     name = "ISO 9660"
@@ -994,6 +1297,7 @@ formats! {
     media_type = "application/java-archive"
     extension = "jar"
     kind = Package
+    feature = "reader-zip"
 
     format = JavaClass
     name = "Java Class"
@@ -1084,12 +1388,29 @@ formats! {
     extension = "json"
     kind = Syndication
 
+    format = JsonWebKeySet
+    name = "JSON Web Key Set"
+    short_name = "JWKS"
+    media_type = "application/jwk-set+json"
+    extension = "json"
+    kind = Application
+    feature = "reader-json"
+
+    format = JsonWebToken
+    name = "JSON Web Token"
+    short_name = "JWT"
+    media_type = "application/jwt"
+    extension = "jwt"
+    kind = Application
+    feature = "reader-json"
+
     format = KeyholeMarkupLanguage
     name = "Keyhole Markup Language"
     short_name = "KML"
     media_type = "application/vnd.google-earth.kml+xml"
     extension = "kml"
     kind = Geospatial
+    feature = "reader-xml"
 
     format = KeyholeMarkupLanguageZipped
     name = "Keyhole Markup Language Zipped"
@@ -1097,6 +1418,7 @@ formats! {
     media_type = "application/vnd.google-earth.kmz"
     extension = "kmz"
     kind = Geospatial
+    feature = "reader-zip"
 
     format = KhronosTexture
     name = "Khronos Texture"
@@ -1152,6 +1474,7 @@ formats! {
     media_type = "application/x-dosexec"
     extension = "exe"
     kind = Executable
+    feature = "reader-exe"
 
     format = LlvmBitcode
     name = "LLVM Bitcode"
@@ -1185,6 +1508,12 @@ formats! {
     extension = "lz4"
     kind = Compression
 
+    format = Lz4Legacy
+    name = "LZ4 Legacy"
+    media_type = "application/x-lz4"
+    extension = "lz4"
+    kind = Compression
+
     format = Lzip
     name = "lzip"
     short_name = "LZ"
@@ -1238,6 +1567,15 @@ formats! {
     media_type = "application/mathml+xml"
     extension = "mathml"
     kind = Application
+    feature = "reader-xml"
+
+    /// Version 7.3 files are HDF5 containers and are not detected as such.
+    format = MatlabData
+    name = "MATLAB Data"
+    short_name = "MAT"
+    media_type = "application/x-matlab-data"
+    extension = "mat"
+    kind = Application
 
     format = Matroska3dVideo
     name = "Matroska 3D Video"
@@ -1245,6 +1583,7 @@ formats! {
     media_type = "video/x-matroska"
     extension = "mk3d"
     kind = Video
+    feature = "reader-ebml"
 
     format = MatroskaAudio
     name = "Matroska Audio"
@@ -1252,6 +1591,7 @@ formats! {
     media_type = "audio/x-matroska"
     extension = "mka"
     kind = Audio
+    feature = "reader-ebml"
 
     format = MatroskaSubtitles
     name = "Matroska Subtitles"
@@ -1259,6 +1599,7 @@ formats! {
     media_type = "application/x-matroska"
     extension = "mks"
     kind = Subtitle
+    feature = "reader-ebml"
 
     format = MatroskaVideo
     name = "Matroska Video"
@@ -1266,6 +1607,7 @@ formats! {
     media_type = "video/x-matroska"
     extension = "mkv"
     kind = Video
+    feature = "reader-ebml"
 
     format = MayaAscii
     name = "Maya ASCII"
@@ -1281,6 +1623,13 @@ formats! {
     extension = "mb"
     kind = Model
 
+    format = MediaDescriptorSidecar
+    name = "Media Descriptor Sidecar"
+    short_name = "MDS"
+    media_type = "application/x-mds"
+    extension = "mds"
+    kind = Disk
+
     format = MegaDriveRom
     name = "Mega Drive ROM"
     short_name = "MD"
@@ -1322,6 +1671,7 @@ formats! {
     media_type = "video/x-ms-asf"
     extension = "dvr-ms"
     kind = Video
+    feature = "reader-asf"
 
     format = MicrosoftDirectdrawSurface
     name = "Microsoft DirectDraw Surface"
@@ -1336,6 +1686,7 @@ formats! {
     media_type = "application/vnd.ms-excel"
     extension = "xls"
     kind = Document
+    feature = "reader-cfb"
 
     format = MicrosoftPowerpointPresentation
     name = "Microsoft PowerPoint Presentation"
@@ -1343,6 +1694,7 @@ formats! {
     media_type = "application/vnd.ms-powerpoint"
     extension = "ppt"
     kind = Document
+    feature = "reader-cfb"
 
     format = MicrosoftProjectPlan
     name = "Microsoft Project Plan"
@@ -1350,6 +1702,7 @@ formats! {
     media_type = "application/vnd.ms-project"
     extension = "mpp"
     kind = Document
+    feature = "reader-cfb"
 
     format = MicrosoftPublisherDocument
     name = "Microsoft Publisher Document"
@@ -1357,6 +1710,7 @@ formats! {
     media_type = "application/vnd.ms-publisher"
     extension = "pub"
     kind = Document
+    feature = "reader-cfb"
 
     format = MicrosoftReader
     name = "Microsoft Reader"
@@ -1371,6 +1725,7 @@ formats! {
     media_type = "application/x-msi"
     extension = "msi"
     kind = Package
+    feature = "reader-cfb"
 
     format = MicrosoftVirtualHardDisk
     name = "Microsoft Virtual Hard Disk"
@@ -1392,6 +1747,7 @@ formats! {
     media_type = "application/vnd.visio"
     extension = "vsd"
     kind = Document
+    feature = "reader-cfb"
 
     format = MicrosoftVisualStudioExtension
     name = "Microsoft Visual Studio Extension"
@@ -1399,6 +1755,7 @@ formats! {
     media_type = "application/vsix"
     extension = "vsix"
     kind = Package
+    feature = "reader-zip"
 
     format = MicrosoftVisualStudioSolution
     name = "Microsoft Visual Studio Solution"
@@ -1413,6 +1770,7 @@ formats! {
     media_type = "application/msword"
     extension = "doc"
     kind = Document
+    feature = "reader-cfb"
 
     format = MicrosoftWorks6Spreadsheet
     name = "Microsoft Works 6 Spreadsheet"
@@ -1420,6 +1778,7 @@ formats! {
     media_type = "application/vnd.ms-works"
     extension = "xlr"
     kind = Document
+    feature = "reader-cfb"
 
     format = MicrosoftWorksDatabase
     name = "Microsoft Works Database"
@@ -1427,6 +1786,7 @@ formats! {
     media_type = "application/vnd.ms-works-db"
     extension = "wdb"
     kind = Database
+    feature = "reader-cfb"
 
     format = MicrosoftWorksSpreadsheet
     name = "Microsoft Works Spreadsheet"
@@ -1441,6 +1801,7 @@ formats! {
     media_type = "application/vnd.ms-works"
     extension = "wps"
     kind = Document
+    feature = "reader-cfb"
 
     format = MicrosoftWrite
     name = "Microsoft Write"
@@ -1449,6 +1810,14 @@ formats! {
     extension = "wri"
     kind = Document
 
+    format = MidiKaraoke
+    name = "MIDI Karaoke"
+    short_name = "KAR"
+    media_type = "audio/midi"
+    extension = "kar"
+    kind = Audio
+    feature = "reader-midi"
+
     format = Mobipocket
     name = "Mobipocket"
     short_name = "MOBI"
@@ -1525,6 +1894,7 @@ formats! {
     media_type = "audio/mp4"
     extension = "mp4"
     kind = Audio
+    feature = "reader-mp4"
 
     format = Mpeg4Part14Subtitles
     name = "MPEG-4 Part 14 Subtitles"
@@ -1532,6 +1902,7 @@ formats! {
     media_type = "application/mp4"
     extension = "mp4"
     kind = Subtitle
+    feature = "reader-mp4"
 
     format = Mpeg4Part14Video
     name = "MPEG-4 Part 14 Video"
@@ -1539,6 +1910,7 @@ formats! {
     media_type = "video/mp4"
     extension = "mp4"
     kind = Video
+    feature = "reader-mp4"
 
     format = MpegDashManifest
     name = "MPEG-DASH Manifest"
@@ -1546,6 +1918,21 @@ formats! {
     media_type = "application/dash+xml"
     extension = "mpd"
     kind = Playlist
+    feature = "reader-xml"
+
+    format = MsCompressKwaj
+    name = "MS Compress KWAJ"
+    short_name = "KWAJ"
+    media_type = "application/x-ms-compress-kwaj"
+    extension = "_"
+    kind = Compression
+
+    format = MsCompressSzdd
+    name = "MS Compress SZDD"
+    short_name = "SZDD"
+    media_type = "application/x-ms-compress-szdd"
+    extension = "_"
+    kind = Compression
 
     format = MsDosBatch
     name = "MS-DOS Batch"
@@ -1559,6 +1946,7 @@ formats! {
     short_name = "EXE"
     media_type = "application/x-dosexec"
     extension = "exe"
+    preferred = true
     kind = Executable
 
     format = Mtv
@@ -1593,6 +1981,7 @@ formats! {
     media_type = "application/vnd.recordare.musicxml+xml"
     extension = "musicxml"
     kind = Application
+    feature = "reader-xml"
 
     format = MusicxmlZipped
     name = "MusicXML Zipped"
@@ -1600,6 +1989,7 @@ formats! {
     media_type = "application/vnd.recordare.musicxml"
     extension = "mxl"
     kind = Application
+    feature = "reader-zip"
 
     format = NeoGeoPocketColorRom
     name = "Neo Geo Pocket Color ROM"
@@ -1621,6 +2011,7 @@ formats! {
     media_type = "application/x-ms-ne-executable"
     extension = "exe"
     kind = Executable
+    feature = "reader-exe"
 
     format = NikonElectronicFile
     name = "Nikon Electronic File"
@@ -1629,6 +2020,13 @@ formats! {
     extension = "nef"
     kind = Image
 
+    format = Nintendo3dsRom
+    name = "Nintendo 3DS ROM"
+    short_name = "3DS"
+    media_type = "application/x-nintendo-3ds-rom"
+    extension = "3ds"
+    kind = Rom
+
     format = Nintendo64Rom
     name = "Nintendo 64 ROM"
     short_name = "Z64"
@@ -1671,12 +2069,29 @@ formats! {
     extension = "xci"
     kind = Rom
 
+    format = NullsoftScriptableInstallSystem
+    name = "Nullsoft Scriptable Install System"
+    short_name = "NSIS"
+    media_type = "application/x-nsis"
+    extension = "exe"
+    kind = Executable
+    feature = "reader-exe"
+
+    format = OciImageLayout
+    name = "OCI Image Layout"
+    media_type = "application/vnd.oci.image.layout.v1+tar"
+    extension = "tar"
+    kind = Archive
+    feature = "reader-tar"
+
     format = OfficeOpenXmlDocument
     name = "Office Open XML Document"
     short_name = "DOCX"
     media_type = "application/vnd.openxmlformats-officedocument.wordprocessingml.document"
     extension = "docx"
+    preferred = true
     kind = Document
+    feature = "reader-zip"
 
     format = OfficeOpenXmlDrawing
     name = "Office Open XML Drawing"
@@ -1684,6 +2099,7 @@ formats! {
     media_type = "application/vnd.ms-visio.drawing.main+xml"
     extension = "vsdx"
     kind = Document
+    feature = "reader-zip"
 
     format = OfficeOpenXmlPresentation
     name = "Office Open XML Presentation"
@@ -1691,6 +2107,7 @@ formats! {
     media_type = "application/vnd.openxmlformats-officedocument.presentationml.presentation"
     extension = "pptx"
     kind = Document
+    feature = "reader-zip"
 
     format = OfficeOpenXmlSpreadsheet
     name = "Office Open XML Spreadsheet"
@@ -1698,6 +2115,7 @@ formats! {
     media_type = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
     extension = "xlsx"
     kind = Document
+    feature = "reader-zip"
 
     format = OggFlac
     name = "Ogg FLAC"
@@ -1761,6 +2179,7 @@ formats! {
     media_type = "application/vnd.oasis.opendocument.database"
     extension = "odb"
     kind = Database
+    feature = "reader-zip"
 
     format = OpendocumentFormula
     name = "OpenDocument Formula"
@@ -1768,6 +2187,7 @@ formats! {
     media_type = "application/vnd.oasis.opendocument.formula"
     extension = "odf"
     kind = Document
+    feature = "reader-zip"
 
     format = OpendocumentFormulaTemplate
     name = "OpenDocument Formula Template"
@@ -1775,6 +2195,7 @@ formats! {
     media_type = "application/vnd.oasis.opendocument.formula-template"
     extension = "otf"
     kind = Document
+    feature = "reader-zip"
 
     format = OpendocumentGraphics
     name = "OpenDocument Graphics"
@@ -1782,6 +2203,7 @@ formats! {
     media_type = "application/vnd.oasis.opendocument.graphics"
     extension = "odg"
     kind = Document
+    feature = "reader-zip"
 
     format = OpendocumentGraphicsTemplate
     name = "OpenDocument Graphics Template"
@@ -1789,6 +2211,7 @@ formats! {
     media_type = "application/vnd.oasis.opendocument.graphics-template"
     extension = "otg"
     kind = Document
+    feature = "reader-zip"
 
     format = OpendocumentPresentation
     name = "OpenDocument Presentation"
@@ -1796,6 +2219,7 @@ formats! {
     media_type = "application/vnd.oasis.opendocument.presentation"
     extension = "odp"
     kind = Document
+    feature = "reader-zip"
 
     format = OpendocumentPresentationTemplate
     name = "OpenDocument Presentation Template"
@@ -1803,6 +2227,7 @@ formats! {
     media_type = "application/vnd.oasis.opendocument.presentation-template"
     extension = "otp"
     kind = Document
+    feature = "reader-zip"
 
     format = OpendocumentSpreadsheet
     name = "OpenDocument Spreadsheet"
@@ -1810,6 +2235,7 @@ formats! {
     media_type = "application/vnd.oasis.opendocument.spreadsheet"
     extension = "ods"
     kind = Document
+    feature = "reader-zip"
 
     format = OpendocumentSpreadsheetTemplate
     name = "OpenDocument Spreadsheet Template"
@@ -1817,6 +2243,7 @@ formats! {
     media_type = "application/vnd.oasis.opendocument.spreadsheet-template"
     extension = "ots"
     kind = Document
+    feature = "reader-zip"
 
     format = OpendocumentText
     name = "OpenDocument Text"
@@ -1824,6 +2251,7 @@ formats! {
     media_type = "application/vnd.oasis.opendocument.text"
     extension = "odt"
     kind = Document
+    feature = "reader-zip"
 
     format = OpendocumentTextMaster
     name = "OpenDocument Text Master"
@@ -1831,6 +2259,7 @@ formats! {
     media_type = "application/vnd.oasis.opendocument.text-master"
     extension = "odm"
     kind = Document
+    feature = "reader-zip"
 
     format = OpendocumentTextMasterTemplate
     name = "OpenDocument Text Master Template"
@@ -1838,6 +2267,7 @@ formats! {
     media_type = "application/vnd.oasis.opendocument.text-master-template"
     extension = "otm"
     kind = Document
+    feature = "reader-zip"
 
     format = OpendocumentTextTemplate
     name = "OpenDocument Text Template"
@@ -1845,6 +2275,7 @@ formats! {
     media_type = "application/vnd.oasis.opendocument.text-template"
     extension = "ott"
     kind = Document
+    feature = "reader-zip"
 
     format = Openexr
     name = "OpenEXR"
@@ -1866,12 +2297,26 @@ formats! {
     media_type = "image/openraster"
     extension = "ora"
     kind = Image
+    feature = "reader-zip"
+
+    format = OpensshPrivateKey
+    name = "OpenSSH Private Key"
+    media_type = "application/x-pem-file"
+    extension = "key"
+    kind = Application
+
+    format = OpensshPublicKey
+    name = "OpenSSH Public Key"
+    media_type = "application/x-ssh-public-key"
+    extension = "pub"
+    kind = Application
 
     format = Opentype
     name = "OpenType"
     short_name = "OTF"
     media_type = "font/otf"
     extension = "otf"
+    preferred = true
     kind = Font
 
     format = OptimizedDalvikExecutable
@@ -1881,6 +2326,14 @@ formats! {
     extension = "dey"
     kind = Executable
 
+    format = OptimizedRowColumnar
+    name = "Optimized Row Columnar"
+    short_name = "ORC"
+    media_type = "application/x-orc"
+    extension = "orc"
+    kind = Application
+    feature = "reader-orc"
+
     format = PanasonicRaw
     name = "Panasonic Raw"
     short_name = "RW2"
@@ -1921,6 +2374,7 @@ formats! {
     short_name = "PEM"
     media_type = "application/x-pem-file"
     extension = "key"
+    preferred = true
     kind = Application
 
     format = PemPublicKey
@@ -1985,12 +2439,32 @@ formats! {
     extension = "pcx"
     kind = Image
 
+    format = Pkcs12
+    name = "PKCS #12"
+    media_type = "application/x-pkcs12"
+    extension = "p12"
+    kind = Certificate
+
+    format = Pkcs7
+    name = "PKCS #7"
+    media_type = "application/x-pkcs7-certificates"
+    extension = "p7b"
+    kind = Certificate
+
     format = PlainText
     name = "Plain Text"
     short_name = "TXT"
     media_type = "text/plain"
     extension = "txt"
     kind = Text
+    feature = "reader-txt"
+
+    format = PlaystationPortableIso
+    name = "PlayStation Portable ISO"
+    short_name = "PSP"
+    media_type = "application/x-psp-iso"
+    extension = "iso"
+    kind = Rom
 
     format = Pmarc
     name = "PMarc"
@@ -2027,6 +2501,13 @@ formats! {
     extension = "pbm"
     kind = Image
 
+    format = PortableCompiledFormat
+    name = "Portable Compiled Format"
+    short_name = "PCF"
+    media_type = "application/x-font-pcf"
+    extension = "pcf"
+    kind = Font
+
     format = PortableDocumentFormat
     name = "Portable Document Format"
     short_name = "PDF"
@@ -2040,6 +2521,7 @@ formats! {
     media_type = "application/vnd.microsoft.portable-executable"
     extension = "exe"
     kind = Executable
+    feature = "reader-exe"
 
     format = PortableFloatmap
     name = "Portable FloatMap"
@@ -2076,12 +2558,69 @@ formats! {
     extension = "ps"
     kind = Application
 
+    format = PresentationGraphicStream
+    name = "Presentation Graphic Stream"
+    short_name = "SUP"
+    media_type = "application/x-pgs"
+    extension = "sup"
+    kind = Subtitle
+
+    format = PrinterFontAscii
+    name = "Printer Font ASCII"
+    short_name = "PFA"
+    media_type = "application/x-font-type1"
+    extension = "pfa"
+    kind = Font
+
+    format = PrinterFontBinary
+    name = "Printer Font Binary"
+    short_name = "PFB"
+    media_type = "application/x-font-type1"
+    extension = "pfb"
+    kind = Font
+
+    format = ProjectionWkt
+    name = "Projection WKT"
+    short_name = "PRJ"
+    media_type = "text/x-wkt"
+    extension = "prj"
+    kind = Geospatial
+    feature = "reader-txt"
+
+    format = PrometheusChunks
+    name = "Prometheus Chunks"
+    media_type = "application/x-prometheus-chunks"
+    extension = "chunks"
+    kind = Database
+
+    format = PrometheusIndex
+    name = "Prometheus Index"
+    media_type = "application/x-prometheus-index"
+    extension = "index"
+    kind = Database
+
+    format = PythonEgg
+    name = "Python Egg"
+    short_name = "EGG"
+    media_type = "application/x-python-egg"
+    extension = "egg"
+    kind = Package
+    feature = "reader-zip"
+
     format = PythonScript
     name = "Python Script"
     media_type = "text/x-script.python"
     extension = "py"
     kind = Text
 
+    format = PythonWheel
+    name = "Python Wheel"
+    short_name = "WHL"
+    media_type = "application/x-wheel+zip"
+    extension = "whl"
+    kind = Package
+    feature = "reader-zip"
+
     format = QemuCopyOnWrite
     name = "QEMU Copy On Write"
     short_name = "QCOW"
@@ -2110,6 +2649,20 @@ formats! {
     extension = "qoi"
     kind = Image
 
+    format = RData
+    name = "R Data"
+    short_name = "RDATA"
+    media_type = "application/x-rdata"
+    extension = "rdata"
+    kind = Application
+
+    format = RDataSerialization
+    name = "R Data Serialization"
+    short_name = "RDS"
+    media_type = "application/x-rds"
+    extension = "rds"
+    kind = Application
+
     format = RadianceHdr
     name = "Radiance HDR"
     short_name = "HDR"
@@ -2123,6 +2676,7 @@ formats! {
     media_type = "audio/x-pn-realaudio"
     extension = "ra"
     kind = Audio
+    feature = "reader-rm"
 
     format = ReallySimpleSyndication
     name = "Really Simple Syndication"
@@ -2130,6 +2684,7 @@ formats! {
     media_type = "application/rss+xml"
     extension = "rss"
     kind = Syndication
+    feature = "reader-xml"
 
     format = Realmedia
     name = "RealMedia"
@@ -2144,6 +2699,7 @@ formats! {
     media_type = "video/x-pn-realvideo"
     extension = "rv"
     kind = Video
+    feature = "reader-rm"
 
     format = RedHatPackageManager
     name = "Red Hat Package Manager"
@@ -2152,6 +2708,20 @@ formats! {
     extension = "rpm"
     kind = Package
 
+    format = RedisDatabase
+    name = "Redis Database"
+    short_name = "RDB"
+    media_type = "application/x-redis-database"
+    extension = "rdb"
+    kind = Database
+
+    format = RenpyArchive
+    name = "Ren'Py Archive"
+    short_name = "RPA"
+    media_type = "application/x-renpy-archive"
+    extension = "rpa"
+    kind = Archive
+
     format = RichTextFormat
     name = "Rich Text Format"
     short_name = "RTF"
@@ -2166,6 +2736,14 @@ formats! {
     extension = "rar"
     kind = Archive
 
+    format = RubyGem
+    name = "Ruby Gem"
+    short_name = "GEM"
+    media_type = "application/x-ruby-gem"
+    extension = "gem"
+    kind = Package
+    feature = "reader-tar"
+
     format = RubyScript
     name = "Ruby Script"
     media_type = "text/x-ruby"
@@ -2179,12 +2757,20 @@ formats! {
     extension = "rz"
     kind = Compression
 
+    format = SasData
+    name = "SAS Data"
+    short_name = "SAS7BDAT"
+    media_type = "application/x-sas-data"
+    extension = "sas7bdat"
+    kind = Application
+
     format = ScalableVectorGraphics
     name = "Scalable Vector Graphics"
     short_name = "SVG"
     media_type = "image/svg+xml"
     extension = "svg"
     kind = Image
+    feature = "reader-xml"
 
     format = ScreamTracker3Module
     name = "Scream Tracker 3 Module"
@@ -2193,6 +2779,13 @@ formats! {
     extension = "s3m"
     kind = Audio
 
+    format = SegaGenesisRom
+    name = "Sega Genesis ROM"
+    short_name = "GEN"
+    media_type = "application/x-genesis-rom"
+    extension = "gen"
+    kind = Rom
+
     format = SegaMasterSystemRom
     name = "Sega Master System ROM"
     short_name = "SMS"
@@ -2221,6 +2814,13 @@ formats! {
     extension = "shp"
     kind = Geospatial
 
+    format = ShapefileIndex
+    name = "Shapefile Index"
+    short_name = "SHX"
+    media_type = "application/x-esri-shape-index"
+    extension = "shx"
+    kind = Geospatial
+
     format = ShellScript
     name = "Shell Script"
     media_type = "text/x-shellscript"
@@ -2254,6 +2854,7 @@ formats! {
     media_type = "application/soap+xml"
     extension = "soap"
     kind = Application
+    feature = "reader-xml"
 
     format = Sketchup
     name = "SketchUp"
@@ -2269,6 +2870,13 @@ formats! {
     extension = "swf"
     kind = Application
 
+    format = Snap
+    name = "Snap"
+    media_type = "application/vnd.snap"
+    extension = "snap"
+    kind = Package
+    feature = "reader-squashfs"
+
     format = Snappy
     name = "Snappy"
     media_type = "application/x-snappy-framed"
@@ -2281,6 +2889,7 @@ formats! {
     media_type = "model/x-sldasm"
     extension = "sldasm"
     kind = Model
+    feature = "reader-cfb"
 
     format = SolidworksDrawing
     name = "SolidWorks Drawing"
@@ -2288,6 +2897,7 @@ formats! {
     media_type = "model/x-slddrw"
     extension = "slddrw"
     kind = Model
+    feature = "reader-cfb"
 
     format = SolidworksPart
     name = "SolidWorks Part"
@@ -2295,6 +2905,7 @@ formats! {
     media_type = "model/x-sldprt"
     extension = "sldprt"
     kind = Model
+    feature = "reader-cfb"
 
     format = SonyDsdStreamFile
     name = "Sony DSD Stream File"
@@ -2323,6 +2934,28 @@ formats! {
     media_type = "model/x-scdoc"
     extension = "scdoc"
     kind = Model
+    feature = "reader-zip"
+
+    format = SpirV
+    name = "SPIR-V"
+    media_type = "application/x-spirv"
+    extension = "spv"
+    kind = Executable
+
+    format = SpssData
+    name = "SPSS Data"
+    short_name = "SAV"
+    media_type = "application/x-spss-sav"
+    extension = "sav"
+    kind = Application
+
+    format = SqlScript
+    name = "SQL Script"
+    short_name = "SQL"
+    media_type = "application/sql"
+    extension = "sql"
+    kind = Text
+    feature = "reader-txt"
 
     format = Sqlite3
     name = "SQLite 3"
@@ -2349,6 +2982,7 @@ formats! {
     media_type = "application/vnd.stardivision.calc"
     extension = "sdc"
     kind = Document
+    feature = "reader-cfb"
 
     format = Starchart
     name = "StarChart"
@@ -2356,6 +2990,7 @@ formats! {
     media_type = "application/vnd.stardivision.chart"
     extension = "sds"
     kind = Document
+    feature = "reader-cfb"
 
     format = Stardraw
     name = "StarDraw"
@@ -2363,6 +2998,7 @@ formats! {
     media_type = "application/vnd.stardivision.draw"
     extension = "sda"
     kind = Document
+    feature = "reader-cfb"
 
     format = Starimpress
     name = "StarImpress"
@@ -2370,6 +3006,7 @@ formats! {
     media_type = "application/vnd.stardivision.impress"
     extension = "sdd"
     kind = Document
+    feature = "reader-cfb"
 
     format = Starmath
     name = "StarMath"
@@ -2377,6 +3014,7 @@ formats! {
     media_type = "application/vnd.stardivision.math"
     extension = "smf"
     kind = Document
+    feature = "reader-cfb"
 
     format = Starwriter
     name = "StarWriter"
@@ -2384,6 +3022,14 @@ formats! {
     media_type = "application/vnd.stardivision.writer"
     extension = "sdw"
     kind = Document
+    feature = "reader-cfb"
+
+    format = StataData
+    name = "Stata Data"
+    short_name = "DTA"
+    media_type = "application/x-stata-dta"
+    extension = "dta"
+    kind = Application
 
     format = StereolithographyAscii
     name = "Stereolithography ASCII"
@@ -2419,6 +3065,7 @@ formats! {
     media_type = "application/vnd.sun.xml.calc"
     extension = "sxc"
     kind = Document
+    feature = "reader-zip"
 
     format = SunXmlCalcTemplate
     name = "Sun XML Calc Template"
@@ -2426,6 +3073,7 @@ formats! {
     media_type = "application/vnd.sun.xml.calc.template"
     extension = "stc"
     kind = Document
+    feature = "reader-zip"
 
     format = SunXmlDraw
     name = "Sun XML Draw"
@@ -2433,6 +3081,7 @@ formats! {
     media_type = "application/vnd.sun.xml.draw"
     extension = "sxd"
     kind = Document
+    feature = "reader-zip"
 
     format = SunXmlDrawTemplate
     name = "Sun XML Draw Template"
@@ -2440,6 +3089,7 @@ formats! {
     media_type = "application/vnd.sun.xml.draw.template"
     extension = "std"
     kind = Document
+    feature = "reader-zip"
 
     format = SunXmlImpress
     name = "Sun XML Impress"
@@ -2447,6 +3097,7 @@ formats! {
     media_type = "application/vnd.sun.xml.impress"
     extension = "sxi"
     kind = Document
+    feature = "reader-zip"
 
     format = SunXmlImpressTemplate
     name = "Sun XML Impress Template"
@@ -2454,6 +3105,7 @@ formats! {
     media_type = "application/vnd.sun.xml.impress.template"
     extension = "sti"
     kind = Document
+    feature = "reader-zip"
 
     format = SunXmlMath
     name = "Sun XML Math"
@@ -2461,6 +3113,7 @@ formats! {
     media_type = "application/vnd.sun.xml.math"
     extension = "sxm"
     kind = Document
+    feature = "reader-zip"
 
     format = SunXmlWriter
     name = "Sun XML Writer"
@@ -2468,6 +3121,7 @@ formats! {
     media_type = "application/vnd.sun.xml.writer"
     extension = "sxw"
     kind = Document
+    feature = "reader-zip"
 
     format = SunXmlWriterGlobal
     name = "Sun XML Writer Global"
@@ -2475,6 +3129,7 @@ formats! {
     media_type = "application/vnd.sun.xml.writer.global"
     extension = "sgw"
     kind = Document
+    feature = "reader-zip"
 
     format = SunXmlWriterTemplate
     name = "Sun XML Writer Template"
@@ -2482,6 +3137,29 @@ formats! {
     media_type = "application/vnd.sun.xml.writer.template"
     extension = "stw"
     kind = Document
+    feature = "reader-zip"
+
+    format = SuperNintendoRom
+    name = "Super Nintendo ROM"
+    short_name = "SNES"
+    media_type = "application/vnd.nintendo.snes.rom"
+    extension = "sfc"
+    kind = Rom
+    feature = "reader-sfc"
+
+    format = SynchronizedAccessibleMediaInterchange
+    name = "Synchronized Accessible Media Interchange"
+    short_name = "SAMI"
+    media_type = "application/x-sami"
+    extension = "smi"
+    kind = Subtitle
+    feature = "reader-txt"
+
+    format = SystemdJournal
+    name = "systemd Journal"
+    media_type = "application/vnd.fdo.journal"
+    extension = "journal"
+    kind = Application
 
     format = TagImageFileFormat
     name = "Tag Image File Format"
@@ -2495,6 +3173,7 @@ formats! {
     short_name = "TAR"
     media_type = "application/x-tar"
     extension = "tar"
+    preferred = true
     kind = Archive
 
     format = Tasty
@@ -2523,12 +3202,14 @@ formats! {
     media_type = "application/vnd.ms-package.3dmanufacturing-3dmodel+xml"
     extension = "3mf"
     kind = Model
+    feature = "reader-zip"
 
     format = ThreeDimensionalStudio
     name = "3D Studio"
     short_name = "3DS"
     media_type = "application/x-3ds"
     extension = "3ds"
+    preferred = true
     kind = Model
 
     format = ThreeDimensionalStudioMax
@@ -2537,6 +3218,14 @@ formats! {
     media_type = "application/x-max"
     extension = "max"
     kind = Model
+    feature = "reader-cfb"
+
+    format = Thrift
+    name = "Thrift"
+    media_type = "application/x-thrift"
+    extension = "bin"
+    kind = Application
+    feature = "reader-thrift"
 
     format = TiledMapXml
     name = "Tiled Map XML"
@@ -2544,6 +3233,7 @@ formats! {
     media_type = "application/x-tmx+xml"
     extension = "tmx"
     kind = Application
+    feature = "reader-xml"
 
     format = TiledTilesetXml
     name = "Tiled Tileset XML"
@@ -2551,6 +3241,7 @@ formats! {
     media_type = "application/x-tsx+xml"
     extension = "tsx"
     kind = Application
+    feature = "reader-xml"
 
     format = TimedTextMarkupLanguage
     name = "Timed Text Markup Language"
@@ -2558,6 +3249,7 @@ formats! {
     media_type = "application/ttml+xml"
     extension = "ttml"
     kind = Subtitle
+    feature = "reader-xml"
 
     format = ToolCommandLanguageScript
     name = "Tool Command Language Script"
@@ -2572,6 +3264,7 @@ formats! {
     media_type = "application/vnd.garmin.tcx+xml"
     extension = "tcx"
     kind = Geospatial
+    feature = "reader-xml"
 
     format = Truetype
     name = "TrueType"
@@ -2580,6 +3273,21 @@ formats! {
     extension = "ttf"
     kind = Font
 
+    format = TruetypeCollection
+    name = "TrueType Collection"
+    short_name = "TTC"
+    media_type = "font/collection"
+    extension = "ttc"
+    kind = Font
+
+    format = TruevisionTga
+    name = "Truevision TGA"
+    short_name = "TGA"
+    media_type = "image/x-tga"
+    extension = "tga"
+    kind = Image
+    feature = "reader-tga"
+
     format = UltimateSoundtrackerModule
     name = "Ultimate Soundtracker Module"
     short_name = "MOD"
@@ -2587,6 +3295,20 @@ formats! {
     extension = "mod"
     kind = Audio
 
+    format = UnifiedDiff
+    name = "Unified Diff"
+    media_type = "text/x-diff"
+    extension = "diff"
+    preferred = true
+    kind = Text
+    feature = "reader-txt"
+
+    format = UnityAssetBundle
+    name = "Unity Asset Bundle"
+    media_type = "application/vnd.unity"
+    extension = "unity3d"
+    kind = Archive
+
     format = Universal3d
     name = "Universal 3D"
     short_name = "U3D"
@@ -2614,6 +3336,7 @@ formats! {
     media_type = "model/vnd.usdz+zip"
     extension = "usdz"
     kind = Model
+    feature = "reader-zip"
 
     format = UniversalSubtitleFormat
     name = "Universal Subtitle Format"
@@ -2621,6 +3344,7 @@ formats! {
     media_type = "application/x-usf"
     extension = "usf"
     kind = Subtitle
+    feature = "reader-xml"
 
     format = UnixArchiver
     name = "UNIX archiver"
@@ -2671,6 +3395,13 @@ formats! {
     extension = "vdi"
     kind = Disk
 
+    format = VobsubIndex
+    name = "VobSub Index"
+    short_name = "IDX"
+    media_type = "text/x-vobsub-index"
+    extension = "idx"
+    kind = Subtitle
+
     format = WaveformAudio
     name = "Waveform Audio"
     short_name = "WAV"
@@ -2691,6 +3422,7 @@ formats! {
     media_type = "application/java-archive"
     extension = "war"
     kind = Package
+    feature = "reader-zip"
 
     format = WebOpenFontFormat
     name = "Web Open Font Format"
@@ -2720,6 +3452,12 @@ formats! {
     extension = "wasm"
     kind = Executable
 
+    format = WebassemblyComponent
+    name = "WebAssembly Component"
+    media_type = "application/wasm"
+    extension = "wasm"
+    kind = Executable
+
     format = WebassemblyText
     name = "WebAssembly Text"
     short_name = "WAT"
@@ -2732,6 +3470,7 @@ formats! {
     media_type = "video/webm"
     extension = "webm"
     kind = Video
+    feature = "reader-ebml"
 
     format = Webp
     name = "WebP"
@@ -2739,6 +3478,12 @@ formats! {
     extension = "webp"
     kind = Image
 
+    format = WiiDisc
+    name = "Wii Disc"
+    media_type = "application/x-wii-rom"
+    extension = "iso"
+    kind = Rom
+
     format = WindowsAnimatedCursor
     name = "Windows Animated Cursor"
     short_name = "ANI"
@@ -2752,6 +3497,7 @@ formats! {
     media_type = "application/vnd.ms-appx"
     extension = "appx"
     kind = Package
+    feature = "reader-zip"
 
     format = WindowsBitmap
     name = "Windows Bitmap"
@@ -2781,12 +3527,20 @@ formats! {
     extension = "wim"
     kind = Archive
 
+    format = WindowsJumpList
+    name = "Windows Jump List"
+    media_type = "application/x-ms-jumplist"
+    extension = "automaticDestinations-ms"
+    kind = Application
+    feature = "reader-cfb"
+
     format = WindowsMediaAudio
     name = "Windows Media Audio"
     short_name = "WMA"
     media_type = "audio/x-ms-wma"
     extension = "wma"
     kind = Audio
+    feature = "reader-asf"
 
     format = WindowsMediaPlaylist
     name = "Windows Media Playlist"
@@ -2801,6 +3555,7 @@ formats! {
     media_type = "video/x-ms-wmv"
     extension = "wmv"
     kind = Video
+    feature = "reader-asf"
 
     format = WindowsMetafile
     name = "Windows Metafile"
@@ -2823,12 +3578,19 @@ formats! {
     extension = "lnk"
     kind = Application
 
+    format = WindowsThumbcache
+    name = "Windows Thumbcache"
+    media_type = "application/x-ms-thumbcache"
+    extension = "db"
+    kind = Application
+
     format = WordperfectDocument
     name = "WordPerfect Document"
     short_name = "WPD"
     media_type = "application/vnd.wordperfect"
     extension = "wpd"
     kind = Document
+    feature = "reader-cfb"
 
     format = WordperfectGraphics
     name = "WordPerfect Graphics"
@@ -2836,6 +3598,7 @@ formats! {
     media_type = "application/vnd.wordperfect"
     extension = "wpg"
     kind = Document
+    feature = "reader-cfb"
 
     format = WordperfectMacro
     name = "WordPerfect Macro"
@@ -2844,6 +3607,13 @@ formats! {
     extension = "wpm"
     kind = Document
 
+    format = WorldFile
+    name = "World File"
+    media_type = "text/x-world-file"
+    extension = "wld"
+    kind = Geospatial
+    feature = "reader-txt"
+
     format = XPixmap
     name = "X PixMap"
     short_name = "XPM"
@@ -2856,6 +3626,7 @@ formats! {
     media_type = "application/x-silverlight-app"
     extension = "xap"
     kind = Package
+    feature = "reader-zip"
 
     format = Xbox360Executable
     name = "Xbox 360 Executable"
@@ -2877,6 +3648,7 @@ formats! {
     media_type = "application/xliff+xml"
     extension = "xlf"
     kind = Application
+    feature = "reader-xml"
 
     format = XmlShareablePlaylistFormat
     name = "XML Shareable Playlist Format"
@@ -2884,6 +3656,7 @@ formats! {
     media_type = "application/xspf+xml"
     extension = "xspf"
     kind = Playlist
+    feature = "reader-xml"
 
     format = Xpinstall
     name = "XPInstall"
@@ -2891,6 +3664,7 @@ formats! {
     media_type = "application/x-xpinstall"
     extension = "xpi"
     kind = Package
+    feature = "reader-zip"
 
     format = Xz
     name = "XZ"
@@ -2898,6 +3672,13 @@ formats! {
     extension = "xz"
     kind = Compression
 
+    format = ZeekLog
+    name = "Zeek Log"
+    media_type = "text/x-zeek"
+    extension = "log"
+    kind = Text
+    feature = "reader-txt"
+
     format = Zip
     name = "ZIP"
     media_type = "application/zip"
@@ -0,0 +1,519 @@
+//! Definition of file formats stable identifiers, listed in ascending order.
+//!
+//! Identifiers must never be changed or reused: new file formats are appended at the end.
+
+ids! {
+    Abiword = 1
+    AbiwordTemplate = 2
+    AbstractSyntaxNotationOne = 3
+    ActionsMediaVideo = 4
+    Activemime = 5
+    AdaptableScalableTextureCompression = 6
+    AdaptiveMultiRate = 7
+    AdditiveManufacturingFormat = 8
+    AdobeFlashPlayerAudio = 9
+    AdobeFlashPlayerAudiobook = 10
+    AdobeFlashPlayerProtectedVideo = 11
+    AdobeFlashPlayerVideo = 12
+    AdobeIllustratorArtwork = 13
+    AdobeIndesignDocument = 14
+    AdobeIntegratedRuntime = 15
+    AdobePhotoshopDocument = 16
+    AdvancedAudioCoding = 17
+    AdvancedCompressionEngine = 18
+    AdvancedStreamRedirector = 19
+    AdvancedSystemsFormat = 20
+    Alz = 21
+    AmigaDiskFile = 22
+    AndroidBinaryXml = 23
+    AndroidCompiledResources = 24
+    AndroidPackage = 25
+    AnimatedPortableNetworkGraphics = 26
+    ApacheArrowColumnar = 27
+    ApacheAvroObjectContainer = 28
+    ApacheParquet = 29
+    Appimage = 30
+    AppleDiskImage = 31
+    AppleIconImage = 32
+    AppleItunesAudio = 33
+    AppleItunesAudiobook = 34
+    AppleItunesProtectedAudio = 35
+    AppleItunesVideo = 36
+    AppleQuicktime = 37
+    ArbitraryBinaryData = 38
+    ArchivedByRobertJung = 39
+    Atari7800Rom = 40
+    Atom = 41
+    Au = 42
+    AudioCodec3 = 43
+    AudioInterchangeFileFormat = 44
+    AudioVideoInterleave = 45
+    AudioVisualResearch = 46
+    AutocadDrawing = 47
+    Autodesk123d = 48
+    AutodeskAlias = 49
+    AutodeskAnimator = 50
+    AutodeskAnimatorPro = 51
+    AutodeskInventorAssembly = 52
+    AutodeskInventorDrawing = 53
+    AutodeskInventorPart = 54
+    AutodeskInventorPresentation = 55
+    Av1ImageFileFormat = 56
+    Av1ImageFileFormatSequence = 57
+    BdavMpeg2TransportStream = 58
+    BetterPortableGraphics = 59
+    BinaryJson = 60
+    BitmapFontAscii = 61
+    BitmapFontBinary = 62
+    BittorrentFile = 63
+    Blender = 64
+    BroadBandEbook = 65
+    Bzip = 66
+    Bzip2 = 67
+    Bzip3 = 68
+    Cabinet = 69
+    CanonRaw = 70
+    CanonRaw2 = 71
+    CanonRaw3 = 72
+    CapnProto = 73
+    CdAudio = 74
+    Cinema4d = 75
+    Cineon = 76
+    CircuitDiagramDocument = 77
+    ClojureScript = 78
+    ClonecdControl = 79
+    CommonObjectFileFormat = 80
+    CompoundFileBinary = 81
+    CompressedHunksOfData = 82
+    CompressedIso = 83
+    CondaPackage = 84
+    ContextDiff = 85
+    Cpio = 86
+    CreativeVoice = 87
+    DalvikExecutable = 88
+    DebianBinaryPackage = 89
+    DerCertificate = 90
+    DesignWebFormat = 91
+    DesignWebFormatXps = 92
+    DigitalAssetExchange = 93
+    DigitalImagingAndCommunicationsInMedicine = 94
+    DigitalPictureExchange = 95
+    DirectxIntermediateLanguage = 96
+    DirectxShaderBytecode = 97
+    Djvu = 98
+    DockerImage = 99
+    DrawingExchangeFormatAscii = 100
+    DrawingExchangeFormatBinary = 101
+    Drawio = 102
+    DynamicLinkLibrary = 103
+    ElectronicPublication = 104
+    ElectronicSoftwareDownload = 105
+    EmbeddedOpentype = 106
+    Empty = 107
+    EncapsulatedPostscript = 108
+    EnterpriseApplicationArchive = 109
+    ExecutableAndLinkableFormat = 110
+    ExperimentalComputingFacility = 111
+    Extensible3d = 112
+    ExtensibleArchive = 113
+    ExtensibleBinaryMetaLanguage = 114
+    ExtensibleMarkupLanguage = 115
+    ExtensibleStylesheetLanguageTransformations = 116
+    Farbfeld = 117
+    Fasttracker2ExtendedModule = 118
+    Fictionbook = 119
+    FictionbookZipped = 120
+    Filmbox = 121
+    FlashVideo = 122
+    Flatbuffers = 123
+    FlexibleAndInteroperableDataTransfer = 124
+    FlexibleImageTransportSystem = 125
+    FreeLosslessAudioCodec = 126
+    FreeLosslessImageFormat = 127
+    FujifilmRaw = 128
+    Fusion360 = 129
+    GameBoyAdvanceRom = 130
+    GameBoyColorRom = 131
+    GameBoyRom = 132
+    GameGearRom = 133
+    GamecubeDisc = 134
+    GeographyMarkupLanguage = 135
+    GettextMachineObject = 136
+    GlTransmissionFormatBinary = 137
+    GodotResourcePack = 138
+    GoogleChromeExtension = 139
+    GoogleDraco = 140
+    GpsExchangeFormat = 141
+    GraphicsInterchangeFormat = 142
+    GraphvizDot = 143
+    Gzip = 144
+    HighEfficiencyImageCoding = 145
+    HighEfficiencyImageCodingSequence = 146
+    HighEfficiencyImageFileFormat = 147
+    HighEfficiencyImageFileFormatSequence = 148
+    HttpArchive = 149
+    HypertextMarkupLanguage = 150
+    Icalendar = 151
+    IccProfile = 152
+    Iff8BitSampledVoice = 153
+    ImpulseTrackerModule = 154
+    IndesignMarkupLanguage = 155
+    InitialGraphicsExchangeSpecification = 156
+    InstallshieldCabinet = 157
+    InterQuakeExport = 158
+    InterQuakeModel = 159
+    IosAppStorePackage = 160
+    Iso9660 = 161
+    JavaArchive = 162
+    JavaClass = 163
+    JavaKeystore = 164
+    JointPhotographicExpertsGroup = 165
+    Jpeg2000Codestream = 166
+    Jpeg2000Part1 = 167
+    Jpeg2000Part2 = 168
+    Jpeg2000Part3 = 169
+    Jpeg2000Part6 = 170
+    JpegExtendedRange = 171
+    JpegLs = 172
+    JpegNetworkGraphics = 173
+    JpegXl = 174
+    JsonFeed = 175
+    JsonWebKeySet = 176
+    JsonWebToken = 177
+    KeyholeMarkupLanguage = 178
+    KeyholeMarkupLanguageZipped = 179
+    KhronosTexture = 180
+    KhronosTexture2 = 181
+    Larc = 182
+    Latex = 183
+    LempelZivFiniteStateEntropy = 184
+    LempelZivMarkovChainAlgorithm = 185
+    Lha = 186
+    LinearExecutable = 187
+    LlvmBitcode = 188
+    LongRangeZip = 189
+    LuaBytecode = 190
+    LuaScript = 191
+    Lz4 = 192
+    Lzip = 193
+    Lzop = 194
+    MachO = 195
+    MacosAlias = 196
+    Magicavoxel = 197
+    MagickImageFileFormat = 198
+    MaterialExchangeFormat = 199
+    MathematicalMarkupLanguage = 200
+    Matroska3dVideo = 201
+    MatroskaAudio = 202
+    MatroskaSubtitles = 203
+    MatroskaVideo = 204
+    MayaAscii = 205
+    MayaBinary = 206
+    MediaDescriptorSidecar = 207
+    MegaDriveRom = 208
+    MetaInformationEncapsulation = 209
+    MicrosoftAccess2007Database = 210
+    MicrosoftAccessDatabase = 211
+    MicrosoftCompiledHtmlHelp = 212
+    MicrosoftDigitalVideoRecording = 213
+    MicrosoftDirectdrawSurface = 214
+    MicrosoftExcelSpreadsheet = 215
+    MicrosoftPowerpointPresentation = 216
+    MicrosoftProjectPlan = 217
+    MicrosoftPublisherDocument = 218
+    MicrosoftReader = 219
+    MicrosoftSoftwareInstaller = 220
+    MicrosoftVirtualHardDisk = 221
+    MicrosoftVirtualHardDisk2 = 222
+    MicrosoftVisioDrawing = 223
+    MicrosoftVisualStudioExtension = 224
+    MicrosoftVisualStudioSolution = 225
+    MicrosoftWordDocument = 226
+    MicrosoftWorks6Spreadsheet = 227
+    MicrosoftWorksDatabase = 228
+    MicrosoftWorksSpreadsheet = 229
+    MicrosoftWorksWordProcessor = 230
+    MicrosoftWrite = 231
+    Mobipocket = 232
+    Model3dAscii = 233
+    Model3dBinary = 234
+    MonkeysAudio = 235
+    Mp3Url = 236
+    Mpeg12AudioLayer2 = 237
+    Mpeg12AudioLayer3 = 238
+    Mpeg12Video = 239
+    Mpeg2TransportStream = 240
+    Mpeg4Part14 = 241
+    Mpeg4Part14Audio = 242
+    Mpeg4Part14Subtitles = 243
+    Mpeg4Part14Video = 244
+    MpegDashManifest = 245
+    MsCompressKwaj = 246
+    MsCompressSzdd = 247
+    MsDosBatch = 248
+    MsDosExecutable = 249
+    Mtv = 250
+    MultipleImageNetworkGraphics = 251
+    Musepack = 252
+    MusicalInstrumentDigitalInterface = 253
+    Musicxml = 254
+    MusicxmlZipped = 255
+    NeoGeoPocketColorRom = 256
+    NeoGeoPocketRom = 257
+    NewExecutable = 258
+    NikonElectronicFile = 259
+    Nintendo3dsRom = 260
+    Nintendo64Rom = 261
+    NintendoDsRom = 262
+    NintendoEntertainmentSystemRom = 263
+    NintendoSwitchExecutable = 264
+    NintendoSwitchPackage = 265
+    NintendoSwitchRom = 266
+    NullsoftScriptableInstallSystem = 267
+    OciImageLayout = 268
+    OfficeOpenXmlDocument = 269
+    OfficeOpenXmlDrawing = 270
+    OfficeOpenXmlPresentation = 271
+    OfficeOpenXmlSpreadsheet = 272
+    OggFlac = 273
+    OggMedia = 274
+    OggMultiplexedMedia = 275
+    OggOpus = 276
+    OggSpeex = 277
+    OggTheora = 278
+    OggVorbis = 279
+    OlympusRawFormat = 280
+    OpendocumentDatabase = 281
+    OpendocumentFormula = 282
+    OpendocumentFormulaTemplate = 283
+    OpendocumentGraphics = 284
+    OpendocumentGraphicsTemplate = 285
+    OpendocumentPresentation = 286
+    OpendocumentPresentationTemplate = 287
+    OpendocumentSpreadsheet = 288
+    OpendocumentSpreadsheetTemplate = 289
+    OpendocumentText = 290
+    OpendocumentTextMaster = 291
+    OpendocumentTextMasterTemplate = 292
+    OpendocumentTextTemplate = 293
+    Openexr = 294
+    Opennurbs = 295
+    Openraster = 296
+    OpensshPrivateKey = 297
+    OpensshPublicKey = 298
+    Opentype = 299
+    OptimizedDalvikExecutable = 300
+    PanasonicRaw = 301
+    PcapDump = 302
+    PcapNextGenerationDump = 303
+    PemCertificate = 304
+    PemCertificateSigningRequest = 305
+    PemPrivateKey = 306
+    PemPublicKey = 307
+    PerlScript = 308
+    PersonalStorageTable = 309
+    PgpMessage = 310
+    PgpPrivateKeyBlock = 311
+    PgpPublicKeyBlock = 312
+    PgpSignature = 313
+    PgpSignedMessage = 314
+    PictureExchange = 315
+    Pkcs12 = 316
+    Pkcs7 = 317
+    PlainText = 318
+    PlaystationPortableIso = 319
+    Pmarc = 320
+    PolygonAscii = 321
+    PolygonBinary = 322
+    PortableArbitraryMap = 323
+    PortableBitmap = 324
+    PortableDocumentFormat = 325
+    PortableExecutable = 326
+    PortableFloatmap = 327
+    PortableGraymap = 328
+    PortableNetworkGraphics = 329
+    PortablePixmap = 330
+    Postscript = 331
+    PrometheusChunks = 332
+    PrometheusIndex = 333
+    PythonEgg = 334
+    PythonScript = 335
+    PythonWheel = 336
+    QemuCopyOnWrite = 337
+    QualcommPurevoice = 338
+    QuiteOkAudio = 339
+    QuiteOkImage = 340
+    RadianceHdr = 341
+    Realaudio = 342
+    ReallySimpleSyndication = 343
+    Realmedia = 344
+    Realvideo = 345
+    RedHatPackageManager = 346
+    RedisDatabase = 347
+    RenpyArchive = 348
+    RichTextFormat = 349
+    RoshalArchive = 350
+    RubyGem = 351
+    RubyScript = 352
+    Rzip = 353
+    ScalableVectorGraphics = 354
+    ScreamTracker3Module = 355
+    SegaMasterSystemRom = 356
+    Seqbox = 357
+    SevenZip = 358
+    Shapefile = 359
+    ShellScript = 360
+    ShoutcastPlaylist = 361
+    SiliconGraphicsImage = 362
+    SiliconGraphicsMovie = 363
+    SimpleObjectAccessProtocol = 364
+    Sketchup = 365
+    SmallWebFormat = 366
+    Snap = 367
+    Snappy = 368
+    SolidworksAssembly = 369
+    SolidworksDrawing = 370
+    SolidworksPart = 371
+    SonyDsdStreamFile = 372
+    SonyMovie = 373
+    Soundfont2 = 374
+    SpaceclaimDocument = 375
+    SpirV = 376
+    SqlScript = 377
+    Sqlite3 = 378
+    Squashfs = 379
+    StandardForTheExchangeOfProductModelData = 380
+    Starcalc = 381
+    Starchart = 382
+    Stardraw = 383
+    Starimpress = 384
+    Starmath = 385
+    Starwriter = 386
+    StereolithographyAscii = 387
+    Stuffit = 388
+    StuffitX = 389
+    SubripText = 390
+    SunXmlCalc = 391
+    SunXmlCalcTemplate = 392
+    SunXmlDraw = 393
+    SunXmlDrawTemplate = 394
+    SunXmlImpress = 395
+    SunXmlImpressTemplate = 396
+    SunXmlMath = 397
+    SunXmlWriter = 398
+    SunXmlWriterGlobal = 399
+    SunXmlWriterTemplate = 400
+    SuperNintendoRom = 401
+    SystemdJournal = 402
+    TagImageFileFormat = 403
+    TapeArchive = 404
+    Tasty = 405
+    ThirdGenerationPartnershipProject = 406
+    ThirdGenerationPartnershipProject2 = 407
+    ThreeDimensionalManufacturingFormat = 408
+    ThreeDimensionalStudio = 409
+    ThreeDimensionalStudioMax = 410
+    Thrift = 411
+    TiledMapXml = 412
+    TiledTilesetXml = 413
+    TimedTextMarkupLanguage = 414
+    ToolCommandLanguageScript = 415
+    TrainingCenterXml = 416
+    Truetype = 417
+    UltimateSoundtrackerModule = 418
+    UnifiedDiff = 419
+    UnityAssetBundle = 420
+    Universal3d = 421
+    UniversalSceneDescriptionAscii = 422
+    UniversalSceneDescriptionBinary = 423
+    UniversalSceneDescriptionZipped = 424
+    UniversalSubtitleFormat = 425
+    UnixArchiver = 426
+    UnixCompress = 427
+    Vcalendar = 428
+    Vcard = 429
+    VirtualMachineDisk = 430
+    VirtualRealityModelingLanguage = 431
+    VirtualboxVirtualDiskImage = 432
+    WaveformAudio = 433
+    Wavpack = 434
+    WebApplicationArchive = 435
+    WebOpenFontFormat = 436
+    WebOpenFontFormat2 = 437
+    WebVideoTextTracks = 438
+    WebassemblyBinary = 439
+    WebassemblyComponent = 440
+    WebassemblyText = 441
+    Webm = 442
+    Webp = 443
+    WiiDisc = 444
+    WindowsAnimatedCursor = 445
+    WindowsAppPackage = 446
+    WindowsBitmap = 447
+    WindowsCursor = 448
+    WindowsIcon = 449
+    WindowsImagingFormat = 450
+    WindowsMediaAudio = 451
+    WindowsMediaPlaylist = 452
+    WindowsMediaVideo = 453
+    WindowsMetafile = 454
+    WindowsRecordedTvShow = 455
+    WindowsShortcut = 456
+    WordperfectDocument = 457
+    WordperfectGraphics = 458
+    WordperfectMacro = 459
+    XPixmap = 460
+    Xap = 461
+    Xbox360Executable = 462
+    XboxExecutable = 463
+    XmlLocalizationInterchangeFileFormat = 464
+    XmlShareablePlaylistFormat = 465
+    Xpinstall = 466
+    Xz = 467
+    Zip = 468
+    Zoo = 469
+    Zpaq = 470
+    Zstandard = 471
+    EncryptedOfficeOpenXml = 472
+    HangulWordProcessor = 473
+    Coreldraw = 474
+    EnhancedMetafile = 475
+    WindowsThumbcache = 476
+    WindowsJumpList = 477
+    Lz4Legacy = 478
+    CompressedTape = 479
+    TruevisionTga = 480
+    CoreAudioFormat = 481
+    AudioInterchangeFileFormatCompressed = 482
+    InterchangeFileFormat = 483
+    InterleavedBitmap = 484
+    IffAnimation = 485
+    DownloadableSounds = 486
+    MidiKaraoke = 487
+    ShapefileIndex = 488
+    ProjectionWkt = 489
+    EsriAsciiGrid = 490
+    WorldFile = 491
+    ApacheArrowStream = 492
+    OptimizedRowColumnar = 493
+    FeatherV1 = 494
+    DuckdbDatabase = 495
+    StataData = 496
+    SpssData = 497
+    SasData = 498
+    RData = 499
+    RDataSerialization = 500
+    MatlabData = 501
+    ZeekLog = 502
+    TruetypeCollection = 503
+    GlyphBitmapDistributionFormat = 504
+    PortableCompiledFormat = 505
+    PrinterFontAscii = 506
+    PrinterFontBinary = 507
+    AdobeFontMetrics = 508
+    SynchronizedAccessibleMediaInterchange = 509
+    PresentationGraphicStream = 510
+    VobsubIndex = 511
+    Base64 = 512
+    SegaGenesisRom = 513
+}
@@ -48,7 +48,24 @@ All features below are disabled by default.
 
 ## Ecosystem features
 
+- `ffi` - Exposes C bindings of the core detection functions in the `ffi` module.
+- `rayon` - Reads the files in parallel in [`FileFormat::from_paths`], using rayon.
 - `serde` - Adds the ability to serialize and deserialize a [`FileFormat`] and [`Kind`] using serde.
+- `wasm` - Exposes JavaScript bindings of the core detection functions in the `wasm` module, using
+  wasm-bindgen.
+
+## Decompression features
+
+These features enable the decompression of the first bytes of compressed files to determine the
+file format of their content, when requested with [`Detector::inner`].
+
+- `decompress` - Enables all decompression features.
+- `decompress-bzip2` - Decompresses [bzip2 (BZ2)](`FileFormat::Bzip2`) files, using bzip2.
+- `decompress-gzip` - Decompresses [gzip (GZ)](`FileFormat::Gzip`) files, using flate2, which
+  also enables the detection of compressed tape archives.
+  * [Compressed Tape Archive (TGZ)](`FileFormat::CompressedTape`)
+- `decompress-xz` - Decompresses [XZ](`FileFormat::Xz`) files, using xz2.
+- `decompress-zstd` - Decompresses [Zstandard (zstd)](`FileFormat::Zstandard`) files, using ruzstd.
 
 ## Reader features
 
@@ -56,11 +73,26 @@ These features enable the detection of file formats that require a specific read
 identification.
 
 - `reader` - Enables all reader features.
+- `reader-7z` - Enables [7-Zip (7Z)](`FileFormat::SevenZip`) inspection, reporting the
+  [compression method](`Report::compression_method`) and whether the archive is
+  [encrypted](`Report::encrypted`).
+- `reader-arrow` - Enables [Apache Arrow Stream](`FileFormat::ApacheArrowStream`) detection when
+  the file format is not recognized by its signature. Please note that this feature only detects
+  streams starting with a schema message framed by a continuation marker.
 - `reader-asf` - Enables [Advanced Systems Format (ASF)](`FileFormat::AdvancedSystemsFormat`) based
   file formats detection.
   * [Microsoft Digital Video Recording (DVR-MS)](`FileFormat::MicrosoftDigitalVideoRecording`)
   * [Windows Media Audio (WMA)](`FileFormat::WindowsMediaAudio`)
   * [Windows Media Video (WMV)](`FileFormat::WindowsMediaVideo`)
+- `reader-asn1` - Enables [Abstract Syntax Notation One (ASN.1)](`FileFormat::AbstractSyntaxNotationOne`)
+  detection when the file format is not recognized by its signature. Please note that this feature
+  only detects files consisting of a single BER or DER-encoded SEQUENCE or SET, as a last resort
+  before text detection.
+- `reader-bson` - Enables [Binary JSON (BSON)](`FileFormat::BinaryJson`) detection when the file
+  format is not recognized by its signature.
+- `reader-capnp` - Enables [Cap'n Proto](`FileFormat::CapnProto`) detection when the file format is
+  not recognized by its signature. Please note that this format lacks a signature, so false
+  positives may occur.
 - `reader-cfb` - Enables [Compound File Binary (CFB)](`FileFormat::CompoundFileBinary`) based file
   formats detection.
   * [3D Studio Max (MAX)](`FileFormat::ThreeDimensionalStudioMax`)
@@ -68,6 +100,8 @@ identification.
   * [Autodesk Inventor Drawing (IDW)](`FileFormat::AutodeskInventorDrawing`)
   * [Autodesk Inventor Part (IPT)](`FileFormat::AutodeskInventorPart`)
   * [Autodesk Inventor Presentation (IPN)](`FileFormat::AutodeskInventorPresentation`)
+  * [Encrypted Office Open XML](`FileFormat::EncryptedOfficeOpenXml`)
+  * [Hangul Word Processor (HWP)](`FileFormat::HangulWordProcessor`)
   * [Microsoft Excel Spreadsheet (XLS)](`FileFormat::MicrosoftExcelSpreadsheet`)
   * [Microsoft PowerPoint Presentation (PPT)](`FileFormat::MicrosoftPowerpointPresentation`)
   * [Microsoft Project Plan (MPP)](`FileFormat::MicrosoftProjectPlan`)
@@ -87,8 +121,12 @@ identification.
   * [StarImpress (SDD)](`FileFormat::Starimpress`)
   * [StarMath (SMF)](`FileFormat::Starmath`)
   * [StarWriter (SDW)](`FileFormat::Starwriter`)
+  * [Windows Jump List](`FileFormat::WindowsJumpList`)
   * [WordPerfect Document (WPD)](`FileFormat::WordperfectDocument`)
   * [WordPerfect Graphics (WPG)](`FileFormat::WordperfectGraphics`)
+- `reader-dxbc` - Enables [DirectX Shader Bytecode (DXBC)](`FileFormat::DirectxShaderBytecode`)
+  based file formats detection.
+  * [DirectX Intermediate Language (DXIL)](`FileFormat::DirectxIntermediateLanguage`)
 - `reader-ebml` - Enables [Extensible Binary Meta Language (EBML)](`FileFormat::ExtensibleBinaryMetaLanguage`)
   based file formats detection.
   * [Matroska 3D Video (MK3D)](`FileFormat::Matroska3dVideo`)
@@ -101,21 +139,71 @@ identification.
   * [Dynamic Link Library (DLL)](`FileFormat::DynamicLinkLibrary`)
   * [Linear Executable (LE)](`FileFormat::LinearExecutable`)
   * [New Executable (NE)](`FileFormat::NewExecutable`)
+  * [Nullsoft Scriptable Install System (NSIS)](`FileFormat::NullsoftScriptableInstallSystem`)
   * [Portable Executable (PE)](`FileFormat::PortableExecutable`)
+- `reader-flatbuffers` - Enables [FlatBuffers](`FileFormat::Flatbuffers`) detection when the file
+  format is not recognized by its signature. Please note that this feature only detects buffers
+  having a file identifier and that false positives may occur since this format lacks a signature.
+- `reader-gb` - Enables [Game Boy ROM (GB)](`FileFormat::GameBoyRom`) and
+  [Game Boy Color ROM (GBC)](`FileFormat::GameBoyColorRom`) validation, checking the Nintendo logo
+  and the header checksum.
+- `reader-iff` - Enables [Interchange File Format (IFF)](`FileFormat::InterchangeFileFormat`)
+  based file formats detection when the file format is not recognized by its signature.
+  * [IFF Animation (ANIM)](`FileFormat::IffAnimation`)
+  * [Interleaved Bitmap (ILBM)](`FileFormat::InterleavedBitmap`)
+- `reader-json` - Enables JSON-based file formats detection when the file format is not recognized
+  by its signature.
+  * [HTTP Archive (HAR)](`FileFormat::HttpArchive`)
+  * [JSON Web Key Set (JWKS)](`FileFormat::JsonWebKeySet`)
+  * [JSON Web Token (JWT)](`FileFormat::JsonWebToken`)
+- `reader-midi` - Enables
+  [Musical Instrument Digital Interface (MIDI)](`FileFormat::MusicalInstrumentDigitalInterface`)
+  based file formats detection, scanning the first tracks for lyrics.
+  * [MIDI Karaoke (KAR)](`FileFormat::MidiKaraoke`)
 - `reader-mp4` - Enables [MPEG-4 Part 14 (MP4)](`FileFormat::Mpeg4Part14`) based file formats
   detection.
   * [MPEG-4 Part 14 Audio (MP4)](`FileFormat::Mpeg4Part14Audio`)
   * [MPEG-4 Part 14 Subtitles (MP4)](`FileFormat::Mpeg4Part14Subtitles`)
   * [MPEG-4 Part 14 Video (MP4)](`FileFormat::Mpeg4Part14Video`)
+- `reader-orc` - Enables [Optimized Row Columnar (ORC)](`FileFormat::OptimizedRowColumnar`)
+  detection when the file format is not recognized by its signature, by checking the postscript at
+  the end of the file.
 - `reader-pdf` - Enables [Portable Document Format (PDF)](`FileFormat::PortableDocumentFormat`)
   based file formats detection.
   * [Adobe Illustrator Artwork (AI)](`FileFormat::AdobeIllustratorArtwork`)
 - `reader-rm` - Enables [RealMedia (RM)](`FileFormat::Realmedia`) based file formats detection.
   * [RealAudio (RA)](`FileFormat::Realaudio`)
   * [RealVideo (RV)](`FileFormat::Realvideo`)
+- `reader-sfc` - Enables [Super Nintendo ROM (SNES)](`FileFormat::SuperNintendoRom`) detection when
+  the file format is not recognized by its signature. Please note that this format lacks a
+  signature, so false positives may occur.
+- `reader-squashfs` - Enables [Squashfs](`FileFormat::Squashfs`) based file formats detection.
+  * [Snap](`FileFormat::Snap`)
+- `reader-tar` - Enables [Tape Archive (TAR)](`FileFormat::TapeArchive`) based file formats
+  detection.
+  * [Docker Image](`FileFormat::DockerImage`)
+  * [OCI Image Layout](`FileFormat::OciImageLayout`)
+  * [Ruby Gem (GEM)](`FileFormat::RubyGem`)
+- `reader-tga` - Enables [Truevision TGA (TGA)](`FileFormat::TruevisionTga`) detection when the
+  file format is not recognized by its signature. Please note that this feature only detects files
+  ending with the footer of the version 2 format.
+- `reader-thrift` - Enables [Thrift](`FileFormat::Thrift`) detection when the file format is not
+  recognized by its signature. Please note that this feature only detects messages encoded with the
+  binary or compact protocol.
 - `reader-txt` - Enables [Plain Text (TXT)](`FileFormat::PlainText`) detection when the file format
   is not recognized by its signature. Please note that this feature only detects files containing
-  ASCII/UTF-8-encoded text.
+  UTF-8, Latin-1 or Windows-1252-encoded text.
+  * [Adobe Font Metrics (AFM)](`FileFormat::AdobeFontMetrics`)
+  * [Base64](`FileFormat::Base64`)
+  * [Context Diff](`FileFormat::ContextDiff`)
+  * [Esri ASCII Grid (ASC)](`FileFormat::EsriAsciiGrid`)
+  * [Graphviz DOT (DOT)](`FileFormat::GraphvizDot`)
+  * [Projection WKT (PRJ)](`FileFormat::ProjectionWkt`)
+  * [SQL Script (SQL)](`FileFormat::SqlScript`)
+  * [Synchronized Accessible Media Interchange (SAMI)](`FileFormat::SynchronizedAccessibleMediaInterchange`)
+  * [Unified Diff](`FileFormat::UnifiedDiff`)
+  * [World File](`FileFormat::WorldFile`)
+  * [Zeek Log](`FileFormat::ZeekLog`)
 - `reader-xml` - Enables [Extensible Markup Language (XML)](`FileFormat::ExtensibleMarkupLanguage`)
   based file formats detection. Please note that these file formats may be detected without the
   feature in certain cases.
@@ -145,12 +233,16 @@ identification.
   * [XML Localization Interchange File Format (XLIFF)](`FileFormat::XmlLocalizationInterchangeFileFormat`)
   * [XML Shareable Playlist Format (XSPF)](`FileFormat::XmlShareablePlaylistFormat`)
   * [draw.io (DRAWIO)](`FileFormat::Drawio`)
-- `reader-zip` - Enables [ZIP](`FileFormat::Zip`)-based file formats detection.
+- `reader-xz` - Enables [XZ](`FileFormat::Xz`) validation, checking the stream header and the
+  stream footer when present, and reports the [integrity check](`Report::integrity_check`).
+- `reader-zip` - Enables [ZIP](`FileFormat::Zip`)-based file formats detection, including archives
+  preceded by other data.
   * [3D Manufacturing Format (3MF)](`FileFormat::ThreeDimensionalManufacturingFormat`)
   * [Adobe Integrated Runtime (AIR)](`FileFormat::AdobeIntegratedRuntime`)
   * [Android Package (APK)](`FileFormat::AndroidPackage`)
   * [Autodesk 123D (123DX)](`FileFormat::Autodesk123d`)
   * [Circuit Diagram Document (CDDX)](`FileFormat::CircuitDiagramDocument`)
+  * [Conda Package (CONDA)](`FileFormat::CondaPackage`)
   * [Design Web Format XPS (DWFX)](`FileFormat::DesignWebFormatXps`)
   * [Electronic Publication (EPUB)](`FileFormat::ElectronicPublication`)
   * [Enterprise Application Archive (EAR)](`FileFormat::EnterpriseApplicationArchive`)
@@ -179,6 +271,8 @@ identification.
   * [OpenDocument Text Master Template (OTM)](`FileFormat::OpendocumentTextMasterTemplate`)
   * [OpenDocument Text Template (OTT)](`FileFormat::OpendocumentTextTemplate`)
   * [OpenRaster (ORA)](`FileFormat::Openraster`)
+  * [Python Egg (EGG)](`FileFormat::PythonEgg`)
+  * [Python Wheel (WHL)](`FileFormat::PythonWheel`)
   * [SpaceClaim Document (SCDOC)](`FileFormat::SpaceclaimDocument`)
   * [Sun XML Calc (SXC)](`FileFormat::SunXmlCalc`)
   * [Sun XML Calc Template (STC)](`FileFormat::SunXmlCalcTemplate`)
@@ -196,23 +290,35 @@ identification.
   * [XAP](`FileFormat::Xap`)
   * [XPInstall (XPI)](`FileFormat::Xpinstall`)
   * [iOS App Store Package (IPA)](`FileFormat::IosAppStorePackage`)
+- `reader-zstd` - Enables [LZ4](`FileFormat::Lz4`) and [LZ4 Legacy](`FileFormat::Lz4Legacy`)
+  detection past leading skippable frames, which are otherwise reported as
+  [Zstandard (zstd)](`FileFormat::Zstandard`).
 */
 
 #![deny(missing_docs)]
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "ffi"), forbid(unsafe_code))]
+#![cfg_attr(feature = "ffi", deny(unsafe_code))]
 
 #[macro_use]
 mod macros;
 
+#[cfg(feature = "ffi")]
+#[allow(unsafe_code)]
+pub mod ffi;
 mod formats;
+mod ids;
 mod readers;
 mod signatures;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 use std::{
-    fmt::{self, Display, Formatter},
+    collections::HashMap,
+    fmt::{self, Debug, Display, Formatter},
     fs::File,
-    io::{Cursor, Read, Result, Seek},
-    path::Path,
+    io::{Cursor, Error, ErrorKind, Read, Result, Seek},
+    path::{Path, PathBuf},
+    str::FromStr,
 };
 
 pub use formats::FileFormat;
@@ -220,6 +326,10 @@ pub use formats::FileFormat;
 impl FileFormat {
     /// Determines file format from bytes.
     ///
+    /// The whole slice is available to the readers, including those inspecting the end of the
+    /// file, such as the ZIP reader. A memory-mapped file can therefore be passed as is, and is
+    /// detected like with [`FileFormat::from_file`].
+    ///
     /// # Examples
     ///
     /// Detects from the first bytes of a
@@ -247,6 +357,52 @@ impl FileFormat {
         Self::from(bytes)
     }
 
+    /// Determines file format from bytes, along with the confidence of the detection.
+    ///
+    /// The file format is the same as the one returned by [`FileFormat::from_bytes`]. The
+    /// confidence is [`Confidence::Exact`] for a signature of at least 4 bytes, or the empty file,
+    /// [`Confidence::Heuristic`] for a shorter signature or a file format recognized by its
+    /// structure or content only, and [`Confidence::Fallback`] for the
+    /// [default value](`FileFormat::default`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use file_format::{Confidence, FileFormat};
+    ///
+    /// let result = FileFormat::from_bytes_with_confidence(b"\x89\x50\x4E\x47\x0D\x0A\x1A\x0A");
+    /// assert_eq!(result, (FileFormat::PortableNetworkGraphics, Confidence::Exact));
+    ///
+    /// let result = FileFormat::from_bytes_with_confidence(&[0; 1000]);
+    /// assert_eq!(result, (FileFormat::ArbitraryBinaryData, Confidence::Fallback));
+    ///```
+    pub fn from_bytes_with_confidence(bytes: &[u8]) -> (Self, Confidence) {
+        // Minimum length of a signature to be trusted on its own.
+        const EXACT_LENGTH_MIN: usize = 4;
+
+        if bytes.is_empty() {
+            return (Self::Empty, Confidence::Exact);
+        }
+        let prefix = &bytes[..bytes.len().min(readers::PartialBuffer::SIZE)];
+        if let Some((format, _, length)) = Self::from_signature(prefix) {
+            if let Ok(format) = Self::from_format_reader(format, Cursor::new(bytes)) {
+                let confidence = if length >= EXACT_LENGTH_MIN {
+                    Confidence::Exact
+                } else {
+                    Confidence::Heuristic
+                };
+                return (format, confidence);
+            }
+        }
+        let format = Self::from_generic_reader(Cursor::new(bytes));
+        let confidence = if format == Self::default() {
+            Confidence::Fallback
+        } else {
+            Confidence::Heuristic
+        };
+        (format, confidence)
+    }
+
     /// Determines file format from a file.
     ///
     /// # Examples
@@ -263,6 +419,50 @@ impl FileFormat {
         Self::from_reader(File::open(path)?)
     }
 
+    /// Determines file formats from several files, returning the results in the order of the paths.
+    ///
+    /// The files are read in parallel when the `rayon` feature is enabled, and one after the other
+    /// otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use file_format::FileFormat;
+    /// use std::path::PathBuf;
+    ///
+    /// let results = FileFormat::from_paths(vec![
+    ///     PathBuf::from("fixtures/video/sample.avi"),
+    ///     PathBuf::from("fixtures/video/missing.avi"),
+    /// ]);
+    /// assert_eq!(results[0].1.as_ref().ok(), Some(&FileFormat::AudioVideoInterleave));
+    /// assert!(results[1].1.is_err());
+    ///```
+    pub fn from_paths<I: IntoIterator<Item = PathBuf>>(paths: I) -> Vec<(PathBuf, Result<Self>)> {
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            paths
+                .into_iter()
+                .collect::<Vec<_>>()
+                .into_par_iter()
+                .map(|path| {
+                    let format = Self::from_file(&path);
+                    (path, format)
+                })
+                .collect()
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            paths
+                .into_iter()
+                .map(|path| {
+                    let format = Self::from_file(&path);
+                    (path, format)
+                })
+                .collect()
+        }
+    }
+
     /// Determines file format from a reader.
     ///
     /// # Examples
@@ -274,19 +474,430 @@ impl FileFormat {
     /// assert_eq!(format, FileFormat::Empty);
     /// # Ok::<(), std::io::Error>(())
     ///```
-    pub fn from_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
-        // Creates and fills a buffer.
-        let mut buffer = [0; 36870];
-        let bytes_read = reader.read(&mut buffer)?;
-
-        // Determines file format.
-        Ok(if bytes_read == 0 {
-            Self::Empty
-        } else if let Some(format) = Self::from_signature(&buffer[..bytes_read]) {
-            Self::from_format_reader(format, &mut reader)
-                .unwrap_or_else(|_| Self::from_generic_reader(&mut reader))
-        } else {
-            Self::from_generic_reader(&mut reader)
+    #[inline]
+    pub fn from_reader<R: Read + Seek>(reader: R) -> Result<Self> {
+        // Uses a buffer on the stack rather than allocating the one of a detector.
+        let mut bytes = [0; readers::PartialBuffer::SIZE];
+        Detector::unbuffered()
+            .inspect(&mut bytes, reader, false)
+            .map(|report| report.format())
+    }
+
+    /// Determines file format from a reader which cannot seek, such as a socket or a decompressor.
+    ///
+    /// Only the first 36870 bytes are read, which cover the farthest signature. When the stream
+    /// ends within them, the result is identical to the one of [`FileFormat::from_bytes`].
+    /// Otherwise, the readers only see these first bytes, so the file formats recognized from the
+    /// end of the file may degrade to a coarser answer:
+    ///
+    /// - ZIP-based file formats (e.g. [DOCX](`FileFormat::OfficeOpenXmlDocument`) or
+    ///   [JAR](`FileFormat::JavaArchive`)) may be reported as [ZIP](`FileFormat::Zip`).
+    /// - CFB-based file formats (e.g. [DOC](`FileFormat::MicrosoftWordDocument`) or
+    ///   [MSI](`FileFormat::MicrosoftSoftwareInstaller`)) may be reported as
+    ///   [CFB](`FileFormat::CompoundFileBinary`).
+    /// - The file formats without signature that are only recognized from their footer (e.g.
+    ///   [TGA](`FileFormat::TruevisionTga`)) or their full structure may not be recognized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use file_format::FileFormat;
+    ///
+    /// let stream: &[u8] = b"\x89\x50\x4E\x47\x0D\x0A\x1A\x0A";
+    /// let format = FileFormat::from_read(stream)?;
+    /// assert_eq!(format, FileFormat::PortableNetworkGraphics);
+    /// # Ok::<(), std::io::Error>(())
+    ///```
+    pub fn from_read<R: Read>(reader: R) -> Result<Self> {
+        // Reads the first bytes of the stream.
+        let mut bytes = Vec::new();
+        reader
+            .take(readers::PartialBuffer::SIZE as u64)
+            .read_to_end(&mut bytes)?;
+        if bytes.len() < readers::PartialBuffer::SIZE {
+            return Ok(Self::from_bytes(&bytes));
+        }
+
+        // Falls back to the signature when the readers cannot tell from the first bytes only.
+        Ok(match Self::from_signature(&bytes) {
+            Some((format, ..)) => {
+                Self::from_format_reader(format, Cursor::new(&bytes)).unwrap_or(format)
+            }
+            None => Self::from_generic_reader(Cursor::new(&bytes)),
+        })
+    }
+
+    /// Determines file format from a reader, reading up to `capacity` first bytes to check the
+    /// signatures and feed the text heuristics.
+    ///
+    /// It behaves like [`FileFormat::from_reader`], which uses the default capacity of 36870
+    /// bytes, covering the farthest signature. A smaller capacity misses the signatures located
+    /// past it, and a larger one gives more bytes to the text heuristics. The readers validating
+    /// the structure of a file format seek on their own, regardless of the capacity: the ZIP
+    /// reader, for instance, always looks for the end of central directory record at the end of
+    /// the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use file_format::FileFormat;
+    ///
+    /// let format = FileFormat::from_reader_with_capacity(std::io::empty(), 64)?;
+    /// assert_eq!(format, FileFormat::Empty);
+    /// # Ok::<(), std::io::Error>(())
+    ///```
+    #[inline]
+    pub fn from_reader_with_capacity<R: Read + Seek>(reader: R, capacity: usize) -> Result<Self> {
+        Detector::with_capacity(capacity).detect(reader)
+    }
+
+    /// Determines all the file formats present in bytes, starting with the one returned by
+    /// [`FileFormat::from_bytes`].
+    ///
+    /// This is useful to detect polyglot files, which are valid in several file formats at once,
+    /// such as a [GIF](`FileFormat::GraphicsInterchangeFormat`) image followed by a
+    /// [ZIP](`FileFormat::Zip`) archive.
+    ///
+    /// Note: This is a best-effort detection, which currently only looks for archives embedded
+    /// after the first byte, or after the sections of a PE, within the next 16 MB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use file_format::FileFormat;
+    ///
+    /// let formats = FileFormat::detect_overlays(b"GIF89a\0\0\0\0PK\x03\x04");
+    /// assert_eq!(formats, [FileFormat::GraphicsInterchangeFormat, FileFormat::Zip]);
+    ///```
+    pub fn detect_overlays(bytes: &[u8]) -> Vec<Self> {
+        // Determines the primary file format.
+        let mut formats = vec![Self::from_bytes(bytes)];
+
+        // Determines the file format of the embedded archive, if any, ignoring the ones of the
+        // same file format as the outer signature (e.g. the next entries of a ZIP archive).
+        let signature = Self::from_signature(bytes).map(|(format, ..)| format);
+        if let Ok(Some(payload)) = Self::from_payload_reader(Cursor::new(bytes)) {
+            if Some(payload) != signature {
+                let payload =
+                    Self::from_format_reader(payload, Cursor::new(bytes)).unwrap_or(payload);
+                if !formats.contains(&payload) {
+                    formats.push(payload);
+                }
+            }
+        }
+        formats
+    }
+
+    /// Checks whether bytes look like text, using the quick heuristic of the
+    /// [loose text](`Detector::loose_text`) mode.
+    ///
+    /// The first 1024 bytes must contain no NUL byte and at most 5% of control characters other
+    /// than whitespaces. The encoding is not validated, so this is available without the
+    /// `reader-txt` feature and does not tell which text file format it is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use file_format::FileFormat;
+    ///
+    /// assert!(FileFormat::looks_like_text("Grüße, world!\n".as_bytes()));
+    /// assert!(!FileFormat::looks_like_text(b"\x89PNG\r\n\x1A\n\0\0\0\x0DIHDR"));
+    ///```
+    #[inline]
+    pub fn looks_like_text(bytes: &[u8]) -> bool {
+        readers::is_probably_text(bytes)
+    }
+
+    /// Determines file format from bytes, failing if it is not recognized.
+    ///
+    /// Unlike [`FileFormat::from_bytes`], an [`UnknownFormat`] error is returned instead of the
+    /// [default value].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use file_format::{FileFormat, UnknownFormat};
+    ///
+    /// let format = FileFormat::try_from_bytes(b"\x89\x50\x4E\x47\x0D\x0A\x1A\x0A");
+    /// assert_eq!(format, Ok(FileFormat::PortableNetworkGraphics));
+    ///
+    /// let format = FileFormat::try_from_bytes(&[0; 1000]);
+    /// assert_eq!(format, Err(UnknownFormat));
+    ///```
+    ///
+    /// [default value]: FileFormat::default
+    pub fn try_from_bytes(bytes: &[u8]) -> std::result::Result<Self, UnknownFormat> {
+        let format = Self::from_bytes(bytes);
+        if format == Self::default() {
+            return Err(UnknownFormat);
+        }
+        Ok(format)
+    }
+
+    /// Determines file format from a reader, failing if it is not recognized.
+    ///
+    /// Unlike [`FileFormat::from_reader`], an error of [`InvalidData`] kind wrapping an
+    /// [`UnknownFormat`] error is returned instead of the [default value].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use file_format::{FileFormat, UnknownFormat};
+    /// use std::io::{Cursor, ErrorKind};
+    ///
+    /// let error = FileFormat::try_from_reader(Cursor::new([0; 1000])).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::InvalidData);
+    /// assert!(error.get_ref().unwrap().is::<UnknownFormat>());
+    ///```
+    ///
+    /// [`InvalidData`]: std::io::ErrorKind::InvalidData
+    /// [default value]: FileFormat::default
+    pub fn try_from_reader<R: Read + Seek>(reader: R) -> Result<Self> {
+        let format = Self::from_reader(reader)?;
+        if format == Self::default() {
+            return Err(Error::new(ErrorKind::InvalidData, UnknownFormat));
+        }
+        Ok(format)
+    }
+
+    /// Determines file format from an extension, with or without its leading dot.
+    ///
+    /// The lookup is case-insensitive and accepts common aliases (e.g. `jpeg` for `jpg`). When
+    /// several file formats share the extension, the canonical one is returned, the others being
+    /// available from [`FileFormat::from_extension_all`]. The lookup is a binary search in a table
+    /// of the [extensions](`FileFormat::extension`) of all file formats, sorted at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use file_format::FileFormat;
+    ///
+    /// let format = FileFormat::from_extension("pdf");
+    /// assert_eq!(format, Some(FileFormat::PortableDocumentFormat));
+    ///
+    /// let format = FileFormat::from_extension(".JPEG");
+    /// assert_eq!(format, Some(FileFormat::JointPhotographicExpertsGroup));
+    ///
+    /// let format = FileFormat::from_extension("exe");
+    /// assert_eq!(format, Some(FileFormat::MsDosExecutable));
+    ///```
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        Self::extension_formats(extension).next()
+    }
+
+    /// Determines all the file formats sharing an extension, with or without its leading dot.
+    ///
+    /// The lookup follows the same rules as [`FileFormat::from_extension`], the canonical file
+    /// format coming first and the others in alphabetical order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use file_format::FileFormat;
+    ///
+    /// let formats = FileFormat::from_extension_all("iso");
+    /// assert_eq!(
+    ///     formats,
+    ///     [FileFormat::Iso9660, FileFormat::PlaystationPortableIso, FileFormat::WiiDisc],
+    /// );
+    ///
+    /// let formats = FileFormat::from_extension_all("unknown");
+    /// assert!(formats.is_empty());
+    ///```
+    pub fn from_extension_all(extension: &str) -> Vec<Self> {
+        Self::extension_formats(extension).collect()
+    }
+
+    /// Returns the file formats sharing an extension, the canonical one first.
+    fn extension_formats(extension: &str) -> impl Iterator<Item = Self> + '_ {
+        // Common extensions aliasing the ones of the file formats.
+        const ALIASES: &[(&str, &str)] = &[
+            ("aif", "aiff"),
+            ("htm", "html"),
+            ("jpeg", "jpg"),
+            ("midi", "mid"),
+            ("mpeg", "mpg"),
+            ("tif", "tiff"),
+        ];
+
+        // Binary searches the extensions sorted in lowercase, comparing them without allocating.
+        let mut extension = extension.strip_prefix('.').unwrap_or(extension);
+        if let Some(&(_, target)) = ALIASES
+            .iter()
+            .find(|&&(alias, _)| alias.eq_ignore_ascii_case(extension))
+        {
+            extension = target;
+        }
+        let start = Self::EXTENSIONS.partition_point(|&(other, _)| {
+            other
+                .bytes()
+                .map(|byte| byte.to_ascii_lowercase())
+                .lt(extension.bytes().map(|byte| byte.to_ascii_lowercase()))
+        });
+        Self::EXTENSIONS[start..]
+            .iter()
+            .take_while(move |&&(other, _)| other.eq_ignore_ascii_case(extension))
+            .map(|&(_, format)| format)
+    }
+
+    /// Sorts the file formats by lowercase extension, the preferred ones first, keeping the order
+    /// of the others. This is a merge sort, usable at compile time to build
+    /// [`FileFormat::EXTENSIONS`].
+    const fn sort_by_extension<const N: usize>(
+        entries: [(&'static str, Self, bool); N],
+    ) -> [(&'static str, Self); N] {
+        // Checks whether an entry strictly precedes another one.
+        const fn precedes(
+            (extension, _, preferred): (&str, FileFormat, bool),
+            (other_extension, _, other_preferred): (&str, FileFormat, bool),
+        ) -> bool {
+            let (extension, other_extension) = (extension.as_bytes(), other_extension.as_bytes());
+            let mut index = 0;
+            while index < extension.len() && index < other_extension.len() {
+                let byte = extension[index].to_ascii_lowercase();
+                let other_byte = other_extension[index].to_ascii_lowercase();
+                if byte != other_byte {
+                    return byte < other_byte;
+                }
+                index += 1;
+            }
+            if extension.len() != other_extension.len() {
+                return extension.len() < other_extension.len();
+            }
+            preferred && !other_preferred
+        }
+
+        // Merges runs of doubling width, taking from the left run on ties to keep the sort stable.
+        let mut entries = entries;
+        let mut merged = entries;
+        let mut width = 1;
+        while width < N {
+            let mut start = 0;
+            while start < N {
+                let middle = if start + width < N { start + width } else { N };
+                let end = if start + 2 * width < N {
+                    start + 2 * width
+                } else {
+                    N
+                };
+                let (mut left, mut right, mut index) = (start, middle, start);
+                while index < end {
+                    if left < middle && (right == end || !precedes(entries[right], entries[left])) {
+                        merged[index] = entries[left];
+                        left += 1;
+                    } else {
+                        merged[index] = entries[right];
+                        right += 1;
+                    }
+                    index += 1;
+                }
+                start = end;
+            }
+            entries = merged;
+            width *= 2;
+        }
+
+        // Drops the preference flags.
+        let mut sorted = [("", Self::ArbitraryBinaryData); N];
+        let mut index = 0;
+        while index < N {
+            sorted[index] = (entries[index].0, entries[index].1);
+            index += 1;
+        }
+        sorted
+    }
+
+    /// Determines file format from a media type, such as the value of a `Content-Type` header.
+    ///
+    /// The parameters (e.g. `; charset=utf-8`) are ignored and the lookup is case-insensitive, as
+    /// specified by RFC 2045. When several file formats share the media type, the canonical one is
+    /// returned, the others being available from [`FileFormat::from_media_type_all`]. The lookup
+    /// relies on a hash map built once per thread from the
+    /// [media types](`FileFormat::media_type`) of all file formats.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use file_format::FileFormat;
+    ///
+    /// let format = FileFormat::from_media_type("application/pdf");
+    /// assert_eq!(format, Some(FileFormat::PortableDocumentFormat));
+    ///
+    /// let format = FileFormat::from_media_type("Text/HTML; charset=UTF-8");
+    /// assert_eq!(format, Some(FileFormat::HypertextMarkupLanguage));
+    ///
+    /// let format = FileFormat::from_media_type("audio/mpeg");
+    /// assert_eq!(format, Some(FileFormat::Mpeg12AudioLayer3));
+    ///```
+    pub fn from_media_type(media_type: &str) -> Option<Self> {
+        Self::with_media_type_formats(media_type, |formats| formats.first().copied())
+    }
+
+    /// Determines all the file formats sharing a media type.
+    ///
+    /// The lookup follows the same rules as [`FileFormat::from_media_type`], the canonical file
+    /// format coming first and the others in alphabetical order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use file_format::FileFormat;
+    ///
+    /// let formats = FileFormat::from_media_type_all("video/quicktime");
+    /// assert_eq!(formats, [FileFormat::AppleQuicktime, FileFormat::SonyMovie]);
+    ///```
+    pub fn from_media_type_all(media_type: &str) -> Vec<Self> {
+        Self::with_media_type_formats(media_type, <[Self]>::to_vec)
+    }
+
+    /// Calls a function with the file formats sharing a media type, the canonical one first.
+    fn with_media_type_formats<T>(media_type: &str, function: impl FnOnce(&[Self]) -> T) -> T {
+        // File formats preferred over the other ones sharing their media type.
+        const PREFERRED: &[FileFormat] = &[
+            FileFormat::JavaArchive,
+            FileFormat::MatroskaVideo,
+            FileFormat::MicrosoftAccessDatabase,
+            FileFormat::Mpeg12AudioLayer3,
+            FileFormat::Mpeg2TransportStream,
+            FileFormat::Mpeg4Part14,
+            FileFormat::Mpeg4Part14Audio,
+            FileFormat::Mpeg4Part14Video,
+            FileFormat::MsDosExecutable,
+            FileFormat::MusicalInstrumentDigitalInterface,
+            FileFormat::OggTheora,
+            FileFormat::OggVorbis,
+            FileFormat::PemCertificate,
+            FileFormat::PgpPublicKeyBlock,
+            FileFormat::PortableExecutable,
+            FileFormat::UnifiedDiff,
+            FileFormat::WindowsIcon,
+            FileFormat::WordperfectDocument,
+        ];
+
+        thread_local! {
+            // File formats indexed by lowercase media type, the preferred ones first.
+            static MEDIA_TYPES: HashMap<String, Vec<FileFormat>> = {
+                let mut media_types = HashMap::<_, Vec<_>>::new();
+                for &(format, _) in FileFormat::VARIANTS {
+                    media_types
+                        .entry(format.media_type().to_ascii_lowercase())
+                        .or_default()
+                        .push(format);
+                }
+                for formats in media_types.values_mut() {
+                    formats.sort_by_key(|format| !PREFERRED.contains(format));
+                }
+                media_types
+            };
+        }
+
+        let media_type = media_type.split(';').next().unwrap_or_default().trim();
+        MEDIA_TYPES.with(|media_types| {
+            function(
+                media_types
+                    .get(&media_type.to_ascii_lowercase())
+                    .map_or(&[], Vec::as_slice),
+            )
         })
     }
 }
@@ -307,13 +918,673 @@ impl Display for FileFormat {
     }
 }
 
+impl FromStr for FileFormat {
+    type Err = ParseFileFormatError;
+
+    /// Parses a file format from its short name (e.g. `PDF`), its full name (e.g.
+    /// `Portable Document Format`), as returned by [`Display`], or its variant name (e.g.
+    /// `PortableDocumentFormat`), in that order of precedence.
+    ///
+    /// Matching is case-insensitive. A short name shared by several file formats (e.g. `MP4`) is
+    /// ambiguous and therefore skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use file_format::{FileFormat, ParseFileFormatError};
+    ///
+    /// assert_eq!("PNG".parse(), Ok(FileFormat::PortableNetworkGraphics));
+    /// assert_eq!("pdf".parse(), Ok(FileFormat::PortableDocumentFormat));
+    /// assert_eq!("Portable Document Format".parse(), Ok(FileFormat::PortableDocumentFormat));
+    /// assert_eq!("PortableDocumentFormat".parse(), Ok(FileFormat::PortableDocumentFormat));
+    /// assert_eq!("MP4".parse::<FileFormat>(), Err(ParseFileFormatError));
+    ///```
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        // Short names are only accepted when they identify a single file format.
+        let mut formats = Self::all().filter(|format| {
+            format
+                .short_name()
+                .map_or(false, |short_name| short_name.eq_ignore_ascii_case(s))
+        });
+        if let (Some(format), None) = (formats.next(), formats.next()) {
+            return Ok(format);
+        }
+
+        // Full names and variant names are unique.
+        Self::all()
+            .find(|format| format.name().eq_ignore_ascii_case(s))
+            .or_else(|| {
+                Self::VARIANTS
+                    .iter()
+                    .find(|(_, variant)| variant.eq_ignore_ascii_case(s))
+                    .map(|&(format, _)| format)
+            })
+            .ok_or(ParseFileFormatError)
+    }
+}
+
 impl From<&[u8]> for FileFormat {
+    /// Determines file format from bytes, checking the signatures against them directly instead
+    /// of buffering them like [`FileFormat::from_reader`] does, with identical results.
     #[inline]
     fn from(value: &[u8]) -> Self {
-        Self::from_reader(Cursor::new(value)).unwrap_or_default()
+        Self::from_bytes_with_confidence(value).0
+    }
+}
+
+/// An error returned when a file format is not recognized.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UnknownFormat;
+
+impl Display for UnknownFormat {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(formatter, "unknown file format")
     }
 }
 
+impl std::error::Error for UnknownFormat {}
+
+/// An error returned when a file format cannot be parsed from a string.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseFileFormatError;
+
+impl Display for ParseFileFormatError {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(formatter, "unknown file format name")
+    }
+}
+
+impl std::error::Error for ParseFileFormatError {}
+
+/// A configurable file format detector.
+///
+/// It behaves exactly like [`FileFormat::from_reader`] by default, and provides options to alter
+/// the detection process. It also owns the buffer holding the first bytes, which is reused between
+/// detections, so reusing a detector to classify many files saves an allocation per file.
+///
+/// # Examples
+///
+/// ```
+/// use file_format::{Detector, FileFormat};
+///
+/// let mut detector = Detector::new().loose_text(true);
+/// let format = detector.detect(std::io::Cursor::new("Hello, world!"))?;
+/// assert_eq!(format, FileFormat::PlainText);
+/// # Ok::<(), std::io::Error>(())
+///```
+#[derive(Clone)]
+pub struct Detector {
+    buffer: Box<[u8]>,
+    inner: bool,
+    loose_text: bool,
+    payload: bool,
+}
+
+impl Detector {
+    /// Creates a detector with the default options.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a detector with the default options, reading up to `capacity` first bytes to
+    /// check the signatures and feed the text heuristics.
+    ///
+    /// The default capacity (36870 bytes) covers the farthest signature, such as the one of
+    /// [ISO 9660 (ISO)](`FileFormat::Iso9660`) images, which a smaller capacity misses. At least
+    /// one byte is always read, so that an [empty](`FileFormat::Empty`) stream is recognized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use file_format::{Detector, FileFormat};
+    ///
+    /// let mut detector = Detector::with_capacity(4096);
+    /// let format = detector.detect(std::io::Cursor::new(b"\x89PNG\r\n\x1A\n"))?;
+    /// assert_eq!(format, FileFormat::PortableNetworkGraphics);
+    /// # Ok::<(), std::io::Error>(())
+    ///```
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: vec![0; capacity.max(1)].into_boxed_slice(),
+            ..Self::unbuffered()
+        }
+    }
+
+    /// Creates a detector with the default options and no buffer, for a single detection holding
+    /// the first bytes in a buffer of its own.
+    #[inline]
+    fn unbuffered() -> Self {
+        Self {
+            buffer: Box::default(),
+            inner: false,
+            loose_text: false,
+            payload: false,
+        }
+    }
+
+    /// Sets whether compressed files are partially decompressed, and [Base64](`FileFormat::Base64`)
+    /// text partially decoded, to determine the file format of their content, which is then
+    /// reported by [`Report::inner`].
+    ///
+    /// Only the first 1 MB of content is decompressed or decoded, and only for the compression
+    /// formats enabled by the `decompress-*` features.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "decompress-gzip")]
+    /// # {
+    /// use file_format::{Detector, FileFormat};
+    ///
+    /// let file = std::fs::File::open("fixtures/compression/sample2.gz")?;
+    /// let report = Detector::new().inner(true).report(file)?;
+    /// assert_eq!(report.format(), FileFormat::CompressedTape);
+    /// assert_eq!(report.inner(), Some(FileFormat::TapeArchive));
+    /// # }
+    /// # Ok::<(), std::io::Error>(())
+    ///```
+    #[inline]
+    pub fn inner(mut self, enabled: bool) -> Self {
+        self.inner = enabled;
+        self
+    }
+
+    /// Sets whether unrecognized files are checked for [Plain Text (TXT)](`FileFormat::PlainText`)
+    /// using a quick heuristic instead of the `reader-txt` feature.
+    ///
+    /// The heuristic only checks that the first bytes contain no NUL byte and are mostly made of
+    /// printable characters, without validating the encoding. It is faster and looser than the
+    /// `reader-txt` feature, and does not require it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use file_format::{Detector, FileFormat};
+    ///
+    /// let mut detector = Detector::new().loose_text(true);
+    /// let format = detector.detect(std::io::Cursor::new(b"caf\x81 au lait"))?;
+    /// assert_eq!(format, FileFormat::PlainText);
+    /// # Ok::<(), std::io::Error>(())
+    ///```
+    #[inline]
+    pub fn loose_text(mut self, enabled: bool) -> Self {
+        self.loose_text = enabled;
+        self
+    }
+
+    /// Sets whether executables are scanned for an embedded archive, such as the payload of a
+    /// self-extracting archive, which is then reported by [`Report::payload`].
+    ///
+    /// The scan starts at the overlay of a [PE](`FileFormat::PortableExecutable`), past its
+    /// sections, so that the archives stored in its resources are ignored. Only the first 16 MB are
+    /// scanned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use file_format::{Detector, FileFormat};
+    ///
+    /// let mut bytes = b"MZ".to_vec();
+    /// bytes.resize(64, 0);
+    /// bytes.extend_from_slice(b"PK\x03\x04");
+    ///
+    /// let mut detector = Detector::new().payload(true);
+    /// let report = detector.report(std::io::Cursor::new(bytes))?;
+    /// assert_eq!(report.format(), FileFormat::MsDosExecutable);
+    /// assert_eq!(report.payload(), Some(FileFormat::Zip));
+    /// # Ok::<(), std::io::Error>(())
+    ///```
+    #[inline]
+    pub fn payload(mut self, enabled: bool) -> Self {
+        self.payload = enabled;
+        self
+    }
+
+    /// Determines file format from a reader.
+    ///
+    /// Unlike [`Detector::report`], the additional information is not gathered, which saves the
+    /// reads it requires, such as filling the buffer to guess the text encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use file_format::{Detector, FileFormat};
+    ///
+    /// let format = Detector::new().detect(std::io::empty())?;
+    /// assert_eq!(format, FileFormat::Empty);
+    /// # Ok::<(), std::io::Error>(())
+    ///```
+    #[inline]
+    pub fn detect<R: Read + Seek>(&mut self, reader: R) -> Result<FileFormat> {
+        self.inspect_buffered(reader, false)
+            .map(|report| report.format())
+    }
+
+    /// Determines file format from a reader, along with additional information about the detection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use file_format::{Detector, FileFormat, TextEncoding};
+    ///
+    /// let report = Detector::new().loose_text(true).report(std::io::Cursor::new(b"caf\xE9"))?;
+    /// assert_eq!(report.format(), FileFormat::PlainText);
+    /// assert_eq!(report.encoding(), Some(TextEncoding::Latin1));
+    /// # Ok::<(), std::io::Error>(())
+    ///```
+    #[inline]
+    pub fn report<R: Read + Seek>(&mut self, reader: R) -> Result<Report> {
+        self.inspect_buffered(reader, true)
+    }
+
+    /// Inspects a reader using the buffer owned by the detector.
+    fn inspect_buffered<R: Read + Seek>(
+        &mut self,
+        reader: R,
+        with_details: bool,
+    ) -> Result<Report> {
+        let mut bytes = std::mem::take(&mut self.buffer);
+        let report = self.inspect(&mut bytes, reader, with_details);
+        self.buffer = bytes;
+        report
+    }
+
+    /// Determines file format from a reader, holding its first bytes in the specified buffer and
+    /// gathering the additional information of the report only when `with_details` is set, since
+    /// it may require reading further.
+    fn inspect<R: Read + Seek>(
+        &self,
+        bytes: &mut [u8],
+        mut reader: R,
+        with_details: bool,
+    ) -> Result<Report> {
+        // Reads the first bytes, then only the ranges needed to rule out the signatures taking
+        // precedence over the matching one.
+        let mut buffer = readers::PartialBuffer::new(bytes);
+        buffer.read(&mut reader, 0, readers::PartialBuffer::HEAD_SIZE)?;
+        let signature = loop {
+            match FileFormat::from_partial_signature(&buffer) {
+                Ok(signature) => break signature,
+                Err((start, end)) => buffer.read(&mut reader, start, end)?,
+            }
+        };
+
+        // Determines file format, reading the whole buffer only when it is needed.
+        let (format, offset) = if buffer.is_empty() {
+            (FileFormat::Empty, None)
+        } else if let Some((format, offset)) = signature.and_then(|(format, offset)| {
+            FileFormat::from_format_reader(format, &mut reader)
+                .ok()
+                .map(|format| (format, Some(offset)))
+        }) {
+            (format, offset)
+        } else {
+            let bytes = buffer.fill(&mut reader)?;
+            (self.detect_generic(bytes, &mut reader), None)
+        };
+
+        // Guesses the text encoding.
+        let encoding = if with_details && format.kind() == Kind::Text {
+            let capacity = buffer.capacity();
+            let bytes = buffer.fill(&mut reader)?;
+            readers::guess_text_encoding(bytes, bytes.len() == capacity)
+        } else {
+            None
+        };
+
+        // Scans for an embedded archive.
+        let payload = if with_details && self.payload && format.kind() == Kind::Executable {
+            FileFormat::from_payload_reader(&mut reader)?
+        } else {
+            None
+        };
+
+        // Decompresses the content.
+        let inner = if with_details && self.inner {
+            FileFormat::from_inner_reader(format, &mut reader)?
+        } else {
+            None
+        };
+
+        // Inspects the content.
+        let details = if with_details {
+            readers::Details::from_reader(format, &mut reader)
+        } else {
+            readers::Details::default()
+        };
+
+        Ok(Report {
+            format,
+            offset,
+            encoding,
+            payload,
+            inner,
+            compression_method: details.compression_method,
+            encrypted: details.encrypted,
+            integrity_check: details.integrity_check,
+            version: details.version,
+            activity: details.activity,
+            byte_order: details.byte_order,
+            link_type: details.link_type,
+            variable: details.variable,
+        })
+    }
+
+    /// Determines file format from a generic reader, according to the detector options.
+    #[inline]
+    fn detect_generic<R: Read + Seek>(&self, bytes: &[u8], reader: R) -> FileFormat {
+        if !self.loose_text {
+            FileFormat::from_generic_reader(reader)
+        } else if let Some(format) = FileFormat::from_structure_reader(reader) {
+            format
+        } else if readers::is_probably_text(bytes) {
+            FileFormat::PlainText
+        } else {
+            FileFormat::default()
+        }
+    }
+}
+
+impl Debug for Detector {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("Detector")
+            .field("capacity", &self.buffer.len())
+            .field("inner", &self.inner)
+            .field("loose_text", &self.loose_text)
+            .field("payload", &self.payload)
+            .finish()
+    }
+}
+
+impl Default for Detector {
+    #[inline]
+    fn default() -> Self {
+        Self::with_capacity(readers::PartialBuffer::SIZE)
+    }
+}
+
+/// A report of a detection made by a [`Detector`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Report {
+    format: FileFormat,
+    offset: Option<usize>,
+    encoding: Option<TextEncoding>,
+    payload: Option<FileFormat>,
+    inner: Option<FileFormat>,
+    compression_method: Option<CompressionMethod>,
+    encrypted: bool,
+    integrity_check: Option<IntegrityCheck>,
+    version: Option<String>,
+    activity: Option<String>,
+    byte_order: Option<ByteOrder>,
+    link_type: Option<u16>,
+    variable: bool,
+}
+
+impl Report {
+    /// Returns the detected file format.
+    #[inline]
+    pub const fn format(&self) -> FileFormat {
+        self.format
+    }
+
+    /// Returns the offset at which the signature of the file format matched, if the file format
+    /// was recognized by its signature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use file_format::{Detector, FileFormat};
+    ///
+    /// let mut bytes = vec![0; 512];
+    /// bytes[257..265].copy_from_slice(b"ustar\x0000");
+    /// let report = Detector::new().report(std::io::Cursor::new(bytes))?;
+    /// assert_eq!(report.format(), FileFormat::TapeArchive);
+    /// assert_eq!(report.offset(), Some(257));
+    /// # Ok::<(), std::io::Error>(())
+    ///```
+    #[inline]
+    pub const fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// Returns the guessed encoding when the file format is of [Text](`Kind::Text`) kind.
+    ///
+    /// Note: The encoding is guessed from the first bytes using a heuristic, so it may be wrong.
+    /// In particular, a single-byte encoded text is only reported as
+    /// [Windows-1252](`TextEncoding::Windows1252`) if it contains one of the characters that
+    /// Windows-1252 adds to [Latin-1](`TextEncoding::Latin1`), such as curly quotes or the euro
+    /// sign, and other single-byte encodings (e.g. Latin-9) are reported as Latin-1.
+    #[inline]
+    pub const fn encoding(&self) -> Option<TextEncoding> {
+        self.encoding
+    }
+
+    /// Returns the file format of the content embedded in the file, if enabled in the [`Detector`]
+    /// and found.
+    #[inline]
+    pub const fn payload(&self) -> Option<FileFormat> {
+        self.payload
+    }
+
+    /// Returns the file format of the decompressed content of the file, or of the decoded content
+    /// of [Base64](`FileFormat::Base64`) text, if enabled in the [`Detector`] and supported by
+    /// the `decompress-*` features.
+    #[inline]
+    pub const fn inner(&self) -> Option<FileFormat> {
+        self.inner
+    }
+
+    /// Returns the method used to compress the content of the file, if known.
+    ///
+    /// It is only reported for [7-Zip (7Z)](`FileFormat::SevenZip`) archives with the `reader-7z`
+    /// feature, when the headers are stored uncompressed. Otherwise, the method is only described
+    /// inside the compressed headers, which are not decompressed.
+    #[inline]
+    pub const fn compression_method(&self) -> Option<CompressionMethod> {
+        self.compression_method
+    }
+
+    /// Returns whether the content of the file is known to be encrypted.
+    ///
+    /// It is only reported for [7-Zip (7Z)](`FileFormat::SevenZip`) archives with the `reader-7z`
+    /// feature, whose data or headers are encrypted.
+    #[inline]
+    pub const fn encrypted(&self) -> bool {
+        self.encrypted
+    }
+
+    /// Returns the method used to check the integrity of the content of the file, if any.
+    ///
+    /// It is only reported for [XZ](`FileFormat::Xz`) streams with the `reader-xz` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "reader-xz")]
+    /// # {
+    /// use file_format::{Detector, IntegrityCheck};
+    ///
+    /// let file = std::fs::File::open("fixtures/compression/sample2.xz")?;
+    /// let report = Detector::new().report(file)?;
+    /// assert_eq!(report.integrity_check(), Some(IntegrityCheck::Crc64));
+    /// # }
+    /// # Ok::<(), std::io::Error>(())
+    ///```
+    #[inline]
+    pub const fn integrity_check(&self) -> Option<IntegrityCheck> {
+        self.integrity_check
+    }
+
+    /// Returns the version of the file format used by the file, if known.
+    ///
+    /// It is only reported for [GPS Exchange Format (GPX)](`FileFormat::GpsExchangeFormat`),
+    /// [Keyhole Markup Language (KML)](`FileFormat::KeyholeMarkupLanguage`) and
+    /// [Training Center XML (TCX)](`FileFormat::TrainingCenterXml`) files with the `reader-xml`
+    /// feature, from the root element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "reader-xml")]
+    /// # {
+    /// use file_format::{Detector, FileFormat};
+    ///
+    /// let file = std::fs::File::open("fixtures/geospatial/sample1.gpx")?;
+    /// let report = Detector::new().report(file)?;
+    /// assert_eq!(report.format(), FileFormat::GpsExchangeFormat);
+    /// assert_eq!(report.version(), Some("1.1"));
+    /// # }
+    /// # Ok::<(), std::io::Error>(())
+    ///```
+    #[inline]
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// Returns the type of activity recorded in the file, if known.
+    ///
+    /// It is only reported for [Training Center XML (TCX)](`FileFormat::TrainingCenterXml`) files
+    /// with the `reader-xml` feature, from the sport of the first activity (e.g. `Running`).
+    #[inline]
+    pub fn activity(&self) -> Option<&str> {
+        self.activity.as_deref()
+    }
+
+    /// Returns the byte order of the file, if known.
+    ///
+    /// It is only reported for [PCAP Dump (PCAP)](`FileFormat::PcapDump`) files, from the magic
+    /// number, and [PCAP Next Generation Dump (PCAPNG)](`FileFormat::PcapNextGenerationDump`)
+    /// files, from the first section header block.
+    #[inline]
+    pub const fn byte_order(&self) -> Option<ByteOrder> {
+        self.byte_order
+    }
+
+    /// Returns the link-layer type of the captured packets, if known.
+    ///
+    /// It is only reported for [PCAP Dump (PCAP)](`FileFormat::PcapDump`) files, from the global
+    /// header, and [PCAP Next Generation Dump (PCAPNG)](`FileFormat::PcapNextGenerationDump`)
+    /// files, from the first interface description block. The value is a `LINKTYPE_*` number as
+    /// registered by tcpdump (e.g. `1` for Ethernet or `113` for Linux cooked capture).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use file_format::{ByteOrder, Detector, FileFormat};
+    ///
+    /// let file = std::fs::File::open("fixtures/application/sample1.pcap")?;
+    /// let report = Detector::new().report(file)?;
+    /// assert_eq!(report.format(), FileFormat::PcapDump);
+    /// assert_eq!(report.byte_order(), Some(ByteOrder::LittleEndian));
+    /// assert_eq!(report.link_type(), Some(1));
+    /// # Ok::<(), std::io::Error>(())
+    ///```
+    #[inline]
+    pub const fn link_type(&self) -> Option<u16> {
+        self.link_type
+    }
+
+    /// Returns whether the font is known to be a variable font.
+    ///
+    /// It is only reported for [OpenType (OTF)](`FileFormat::Opentype`),
+    /// [TrueType (TTF)](`FileFormat::Truetype`) and
+    /// [TrueType Collection (TTC)](`FileFormat::TruetypeCollection`) fonts holding a font
+    /// variations (`fvar`) table, from the table directory of the first font.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use file_format::{Detector, FileFormat};
+    ///
+    /// let file = std::fs::File::open("fixtures/font/sample2.ttf")?;
+    /// let report = Detector::new().report(file)?;
+    /// assert_eq!(report.format(), FileFormat::Truetype);
+    /// assert!(report.variable());
+    /// # Ok::<(), std::io::Error>(())
+    ///```
+    #[inline]
+    pub const fn variable(&self) -> bool {
+        self.variable
+    }
+}
+
+/// An order in which the bytes of multi-byte values are stored in a file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ByteOrder {
+    /// Big-endian, the most significant byte first.
+    BigEndian,
+    /// Little-endian, the least significant byte first.
+    LittleEndian,
+}
+
+/// A confidence in the file format determined by
+/// [`FileFormat::from_bytes_with_confidence`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Confidence {
+    /// Matched by a signature long enough to identify the file format on its own.
+    Exact,
+    /// Matched by a short signature, or recognized by its structure or content.
+    Heuristic,
+    /// Not recognized, the [default value](`FileFormat::default`) being returned.
+    Fallback,
+}
+
+/// A method used to compress the content of a file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[non_exhaustive]
+pub enum CompressionMethod {
+    /// BCJ, a filter improving the compression of x86 executables.
+    Bcj,
+    /// BCJ2, a filter improving the compression of x86 executables with several output streams.
+    Bcj2,
+    /// bzip2.
+    Bzip2,
+    /// No compression.
+    Copy,
+    /// Deflate.
+    Deflate,
+    /// LZMA.
+    Lzma,
+    /// LZMA2.
+    Lzma2,
+    /// PPMd.
+    Ppmd,
+}
+
+/// A method used to check the integrity of the content of a file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[non_exhaustive]
+pub enum IntegrityCheck {
+    /// CRC-32.
+    Crc32,
+    /// CRC-64.
+    Crc64,
+    /// SHA-256.
+    Sha256,
+}
+
+/// An encoding of a text file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum TextEncoding {
+    /// UTF-8, which includes ASCII.
+    Utf8,
+    /// ISO/IEC 8859-1, also known as Latin-1.
+    Latin1,
+    /// Windows-1252, a superset of Latin-1 adding printable characters in the `0x80..=0x9F` range.
+    Windows1252,
+}
+
 /// A kind of [`FileFormat`].
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -4,29 +4,40 @@
 ///
 /// # Parameters
 ///
+/// - Doc comments: Additional notes about the file format (optional).
 /// - `format`: Variant name representing the file format.
 /// - `name`: Full name of the file format.
 /// - `short_name`: Abbreviated name of the file format (optional).
 /// - `media_type`: Common media type associated with the file format.
 /// - `extension`: Common file extension used for the file format.
+/// - `preferred`: Whether the file format is preferred over the other ones sharing its extension
+///   (optional).
 /// - `kind`: Type or category of the file format.
+/// - `feature`: Reader or decompression feature required to detect the file format (optional).
 macro_rules! formats {
     {
         $(
+            $(#[doc = $doc:literal])*
             format = $format:ident
             name = $name:literal
             $(short_name = $short_name:literal)?
             media_type = $media_type:literal
             extension = $extension:literal
+            $(preferred = $preferred:literal)?
             kind = $kind:ident
+            $(feature = $feature:literal)?
         )*
     } => {
         /// A file format.
-        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        ///
+        /// File formats are ordered alphabetically by variant name, so the relative order of two
+        /// file formats never changes across versions.
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
         #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
         pub enum FileFormat {
             $(
                 #[doc=concat!($name, $(" (", $short_name, ")",)? ".")]
+                $(#[doc=""] #[doc=$doc])*
                 #[doc=concat!("- Media type: `", $media_type, "`")]
                 #[doc=concat!("- Extension: `", $extension, "`")]
                 #[doc=concat!("- Kind: [", stringify!($kind), "](crate::Kind::", stringify!($kind), ")")]
@@ -35,6 +46,39 @@ macro_rules! formats {
         }
 
         impl crate::FileFormat {
+            /// All the file formats along with their variant names, in alphabetical order.
+            pub(crate) const VARIANTS: &'static [(Self, &'static str)] = &[
+                $(
+                    (Self::$format, stringify!($format)),
+                )*
+            ];
+
+            /// All the file formats along with their extensions, sorted by lowercase extension, the
+            /// preferred file format coming first among the ones sharing an extension.
+            pub(crate) const EXTENSIONS: &'static [(&'static str, Self)] =
+                &Self::sort_by_extension([
+                    $(
+                        ($extension, Self::$format, false $(|| $preferred)?),
+                    )*
+                ]);
+
+            /// Returns an iterator over all the file formats, in alphabetical order of their
+            /// variant names.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use file_format::FileFormat;
+            ///
+            /// let extensions = FileFormat::all()
+            ///     .map(|format| (format.extension(), format))
+            ///     .collect::<std::collections::HashMap<_, _>>();
+            /// assert_eq!(extensions["png"], FileFormat::PortableNetworkGraphics);
+            ///```
+            pub fn all() -> impl Iterator<Item = Self> {
+                Self::VARIANTS.iter().map(|&(format, _)| format)
+            }
+
             /// Returns the full name of the file format.
             ///
             /// # Examples
@@ -45,7 +89,7 @@ macro_rules! formats {
             /// let format = FileFormat::Mpeg12AudioLayer3;
             /// assert_eq!(format.name(), "MPEG-1/2 Audio Layer 3");
             ///```
-            pub const fn name(&self) -> &str {
+            pub const fn name(&self) -> &'static str {
                 match self {
                     $(
                         Self::$format => $name,
@@ -66,7 +110,7 @@ macro_rules! formats {
             /// let format = FileFormat::MusicalInstrumentDigitalInterface;
             /// assert_eq!(format.short_name(), Some("MIDI"));
             ///```
-            pub const fn short_name(&self) -> Option<&str> {
+            pub const fn short_name(&self) -> Option<&'static str> {
                 match self {
                     $(
                         $(Self::$format => Some($short_name),)?
@@ -87,7 +131,7 @@ macro_rules! formats {
             /// let format = FileFormat::Zstandard;
             /// assert_eq!(format.media_type(), "application/zstd");
             ///```
-            pub const fn media_type(&self) -> &str {
+            pub const fn media_type(&self) -> &'static str {
                 match self {
                     $(
                         Self::$format => $media_type,
@@ -107,7 +151,7 @@ macro_rules! formats {
             /// let format = FileFormat::WindowsMediaVideo;
             /// assert_eq!(format.extension(), "wmv");
             ///```
-            pub const fn extension(&self) -> &str {
+            pub const fn extension(&self) -> &'static str {
                 match self {
                     $(
                         Self::$format => $extension,
@@ -132,36 +176,199 @@ macro_rules! formats {
                     )*
                 }
             }
+
+            /// Returns the reader feature required to detect the file format, if any.
+            ///
+            /// This is useful to enable only the features needed by an application. Note that
+            /// the [Extensible Markup Language (XML)](`FileFormat::ExtensibleMarkupLanguage`)
+            /// based file formats may be detected without the `reader-xml` feature in certain
+            /// cases.
+            ///
+            /// The file formats requiring a feature are:
+            ///
+            $($(
+                #[doc=concat!(
+                    "- [", $name, "](`FileFormat::", stringify!($format), "`): `", $feature, "`"
+                )]
+            )?)*
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use file_format::FileFormat;
+            ///
+            /// let format = FileFormat::OfficeOpenXmlDocument;
+            /// assert_eq!(format.required_feature(), Some("reader-zip"));
+            ///
+            /// let format = FileFormat::PortableNetworkGraphics;
+            /// assert_eq!(format.required_feature(), None);
+            ///```
+            pub const fn required_feature(&self) -> Option<&'static str> {
+                match self {
+                    $(
+                        $(Self::$format => Some($feature),)?
+                    )*
+                    _ => None,
+                }
+            }
+        }
+
+        #[cfg(feature = "ffi")]
+        impl crate::FileFormat {
+            /// Returns the full name of the file format as a NUL-terminated string.
+            pub(crate) const fn name_with_nul(&self) -> &'static str {
+                match self {
+                    $(
+                        Self::$format => concat!($name, "\0"),
+                    )*
+                }
+            }
+
+            /// Returns the media type of the file format as a NUL-terminated string.
+            pub(crate) const fn media_type_with_nul(&self) -> &'static str {
+                match self {
+                    $(
+                        Self::$format => concat!($media_type, "\0"),
+                    )*
+                }
+            }
+
+            /// Returns the extension of the file format as a NUL-terminated string.
+            pub(crate) const fn extension_with_nul(&self) -> &'static str {
+                match self {
+                    $(
+                        Self::$format => concat!($extension, "\0"),
+                    )*
+                }
+            }
         }
     };
 }
 
-/// Generates the [FileFormat::from_signature](crate::FileFormat::from_signature) function.
+/// Generates the [FileFormat::from_signature](crate::FileFormat::from_signature) and
+/// [FileFormat::from_partial_signature](crate::FileFormat::from_partial_signature) functions.
 ///
 /// # Parameters
 ///
 /// - `format`: Variant name representing the file format.
 /// - `value`: Signature value associated with the format (can be repeated).
-/// - `offset`: Offset to start matching the signature value (defaults to 0 if not specified).
+/// - `offset`: Offset to start matching the signature value (defaults to 0 if not specified). The
+///   offset of the first value is reported as the offset at which the signature matched.
 macro_rules! signatures {
     {
         $(
             format = $format:ident
-            $(value = $($value:literal $(offset = $offset:literal)?),+)+
+            $(
+                value = $first_value:literal $(offset = $first_offset:literal)?
+                $(, $value:literal $(offset = $offset:literal)?)*
+            )+
         )*
     } => {
         impl crate::FileFormat {
-            /// Determines file format by checking its signature.
+            /// Determines file format by checking its signature, along with the offset at which
+            /// the signature matched and the number of bytes it compared.
             #[allow(clippy::int_plus_one)]
-            pub(crate) fn from_signature(bytes: &[u8]) -> Option<Self> {
+            pub(crate) fn from_signature(bytes: &[u8]) -> Option<(Self, usize, usize)> {
                 $(
-                    if $($(bytes.len() >= $($offset +)? $value.len()
-                        && &bytes[$($offset)?..$($offset +)? $value.len()] == $value)&&*)||* {
-                        return Some(Self::$format);
-                    }
+                    $(
+                        if bytes.len() >= $($first_offset +)? $first_value.len()
+                            && &bytes[$($first_offset)?..$($first_offset +)? $first_value.len()]
+                                == $first_value
+                            $(&& bytes.len() >= $($offset +)? $value.len()
+                                && &bytes[$($offset)?..$($offset +)? $value.len()] == $value)*
+                        {
+                            return Some((
+                                Self::$format,
+                                0 $(+ $first_offset)?,
+                                $first_value.len() $(+ $value.len())*,
+                            ));
+                        }
+                    )+
                 )*
                 None
             }
+
+            /// Determines file format by checking its signature against a partially read buffer,
+            /// along with the offset at which the signature matched.
+            ///
+            /// Returns the range of bytes to read when a signature can neither be confirmed nor
+            /// ruled out with the bytes read so far, as it takes precedence over any later one.
+            pub(crate) fn from_partial_signature(
+                buffer: &crate::readers::PartialBuffer,
+            ) -> std::result::Result<Option<(Self, usize)>, (usize, usize)> {
+                $(
+                    $(
+                        let checks = [
+                            buffer.check(0 $(+ $first_offset)?, $first_value)
+                            $(, buffer.check(0 $(+ $offset)?, $value))*
+                        ];
+                        if !checks.contains(&Ok(false)) {
+                            if let Some(&Err(range)) = checks.iter().find(|check| check.is_err()) {
+                                return Err(range);
+                            }
+                            return Ok(Some((Self::$format, 0 $(+ $first_offset)?)));
+                        }
+                    )+
+                )*
+                Ok(None)
+            }
+        }
+    };
+}
+
+/// Generates the [FileFormat::id](crate::FileFormat::id) and
+/// [FileFormat::from_id](crate::FileFormat::from_id) methods.
+///
+/// # Parameters
+///
+/// - `format`: Variant name representing the file format.
+/// - `id`: Stable identifier of the file format.
+macro_rules! ids {
+    {
+        $(
+            $format:ident = $id:literal
+        )*
+    } => {
+        impl crate::FileFormat {
+            /// Returns the stable identifier of the file format.
+            ///
+            /// Unlike the enum discriminant, this identifier never changes across versions, which
+            /// makes it suitable for persistent storage or foreign function interfaces.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use file_format::FileFormat;
+            ///
+            /// let format = FileFormat::Abiword;
+            /// assert_eq!(format.id(), 1);
+            ///```
+            pub const fn id(&self) -> u32 {
+                match self {
+                    $(
+                        Self::$format => $id,
+                    )*
+                }
+            }
+
+            /// Returns the file format associated with a stable identifier, if any.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use file_format::FileFormat;
+            ///
+            /// assert_eq!(FileFormat::from_id(1), Some(FileFormat::Abiword));
+            /// assert_eq!(FileFormat::from_id(0), None);
+            ///```
+            pub const fn from_id(id: u32) -> Option<Self> {
+                match id {
+                    $(
+                        $id => Some(Self::$format),
+                    )*
+                    _ => None,
+                }
+            }
         }
     };
 }
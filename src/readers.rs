@@ -8,32 +8,50 @@ impl crate::FileFormat {
     #[inline]
     pub(crate) fn from_format_reader<R: Read + Seek>(format: Self, reader: R) -> Result<Self> {
         Ok(match format {
+            #[cfg(feature = "decompress-gzip")]
+            Self::Gzip => Self::from_gzip_reader(reader)?,
             #[cfg(feature = "reader-asf")]
             Self::AdvancedSystemsFormat => Self::from_asf_reader(reader)?,
             #[cfg(feature = "reader-cfb")]
             Self::CompoundFileBinary => Self::from_cfb_reader(reader)?,
+            #[cfg(feature = "reader-dxbc")]
+            Self::DirectxShaderBytecode => Self::from_dxbc_reader(reader)?,
             #[cfg(feature = "reader-ebml")]
             Self::ExtensibleBinaryMetaLanguage => Self::from_ebml_reader(reader)?,
             #[cfg(feature = "reader-exe")]
             Self::MsDosExecutable => Self::from_exe_reader(reader)?,
+            #[cfg(feature = "reader-gb")]
+            Self::GameBoyColorRom | Self::GameBoyRom => Self::from_gb_reader(reader)?,
+            #[cfg(feature = "reader-midi")]
+            Self::MusicalInstrumentDigitalInterface => Self::from_midi_reader(reader)?,
             #[cfg(feature = "reader-mp4")]
             Self::Mpeg4Part14 => Self::from_mp4_reader(reader)?,
             #[cfg(feature = "reader-pdf")]
             Self::PortableDocumentFormat => Self::from_pdf_reader(reader)?,
             #[cfg(feature = "reader-rm")]
             Self::Realmedia => Self::from_rm_reader(reader)?,
+            #[cfg(feature = "reader-squashfs")]
+            Self::Squashfs => Self::from_squashfs_reader(reader)?,
+            #[cfg(feature = "reader-tar")]
+            Self::TapeArchive => Self::from_tar_reader(reader)?,
             #[cfg(feature = "reader-xml")]
             Self::ExtensibleMarkupLanguage => Self::from_xml_reader(reader)?,
+            #[cfg(feature = "reader-xz")]
+            Self::Xz => Self::from_xz_reader(reader)?,
             #[cfg(feature = "reader-zip")]
             Self::Zip => Self::from_zip_reader(reader)?,
+            #[cfg(feature = "reader-zstd")]
+            Self::Zstandard => Self::from_zstd_reader(reader)?,
             _ => format,
         })
     }
 
     /// Determines file format from a generic reader.
-    #[allow(unused_variables)]
     #[inline]
-    pub(crate) fn from_generic_reader<R: Read + Seek>(reader: R) -> Self {
+    pub(crate) fn from_generic_reader<R: Read + Seek>(mut reader: R) -> Self {
+        if let Some(format) = Self::from_structure_reader(&mut reader) {
+            return format;
+        }
         #[cfg(feature = "reader-txt")]
         {
             Self::from_txt_reader(reader).unwrap_or_default()
@@ -44,6 +62,239 @@ impl crate::FileFormat {
         }
     }
 
+    /// Determines file format from a generic reader by validating the structure of file formats
+    /// without signature, if any.
+    ///
+    /// The readers checking a prefix of the stream come first, so that unrecognized streams are
+    /// rejected before the readers scanning larger parts of it.
+    #[allow(unused_mut, unused_variables)]
+    #[inline]
+    pub(crate) fn from_structure_reader<R: Read + Seek>(mut reader: R) -> Option<Self> {
+        #[cfg(feature = "reader-iff")]
+        if let Ok(format) = Self::from_iff_reader(&mut reader) {
+            return Some(format);
+        }
+        #[cfg(feature = "reader-orc")]
+        if let Ok(format) = Self::from_orc_reader(&mut reader) {
+            return Some(format);
+        }
+        #[cfg(feature = "reader-arrow")]
+        if let Ok(format) = Self::from_arrow_reader(&mut reader) {
+            return Some(format);
+        }
+        #[cfg(feature = "reader-flatbuffers")]
+        if let Ok(format) = Self::from_flatbuffers_reader(&mut reader) {
+            return Some(format);
+        }
+        #[cfg(feature = "reader-bson")]
+        if let Ok(format) = Self::from_bson_reader(&mut reader) {
+            return Some(format);
+        }
+        #[cfg(feature = "reader-tga")]
+        if let Ok(format) = Self::from_tga_reader(&mut reader) {
+            return Some(format);
+        }
+        #[cfg(feature = "reader-sfc")]
+        if let Ok(format) = Self::from_sfc_reader(&mut reader) {
+            return Some(format);
+        }
+        #[cfg(feature = "reader-thrift")]
+        if let Ok(format) = Self::from_thrift_reader(&mut reader) {
+            return Some(format);
+        }
+        #[cfg(feature = "reader-capnp")]
+        if let Ok(format) = Self::from_capnp_reader(&mut reader) {
+            return Some(format);
+        }
+        #[cfg(feature = "reader-json")]
+        if let Ok(format) = Self::from_json_reader(&mut reader) {
+            return Some(format);
+        }
+        #[cfg(feature = "reader-zip")]
+        if let Ok(format) = Self::from_zip_reader(&mut reader) {
+            return Some(format);
+        }
+        #[cfg(feature = "reader-asn1")]
+        if let Ok(format) = Self::from_asn1_reader(&mut reader) {
+            return Some(format);
+        }
+        None
+    }
+
+    /// Determines the file format of an archive embedded in the reader after its first byte, or
+    /// after the sections of a PE, such as the payload of a self-extracting archive, if any.
+    pub(crate) fn from_payload_reader<R: Read + Seek>(mut reader: R) -> Result<Option<Self>> {
+        // Maximum number of bytes that can be processed by the reader (16 MB).
+        const READ_LIMIT: usize = 16_777_216;
+
+        // Size of each chunk to read (32 KB).
+        const CHUNK_SIZE: usize = 32_768;
+
+        // Size of overlap to keep between chunks.
+        const OVERLAP_SIZE: usize = 7;
+
+        // Markers of the archives that can be embedded.
+        const MARKERS: [&[u8]; 4] = [
+            b"7z\xBC\xAF\x27\x1C",
+            b"MSCF\0\0\0\0",
+            b"PK\x03\x04",
+            b"Rar!\x1A\x07",
+        ];
+
+        // Seeks past the first byte, which belongs to the outer file format, or to the overlay of a
+        // PE, so that the archives stored in its sections (e.g. resources) are ignored.
+        let start_offset = pe_overlay_offset(&mut reader).unwrap_or(1);
+        reader.seek(SeekFrom::Start(start_offset))?;
+
+        // Creates a buffer to hold the chunk of data being read.
+        let mut buffer = [0; OVERLAP_SIZE + CHUNK_SIZE];
+
+        // Reads the data from the stream in chunks.
+        let mut total_bytes_read = 0;
+        while total_bytes_read < READ_LIMIT {
+            // Reads a chunk of the stream into the buffer.
+            let bytes_read = reader.read(&mut buffer[OVERLAP_SIZE..])?;
+            if bytes_read == 0 {
+                break;
+            }
+
+            // Determines the start index for searching the buffer.
+            let start = if total_bytes_read == 0 {
+                OVERLAP_SIZE
+            } else {
+                0
+            };
+
+            // Searches the buffer for the first marker.
+            let index = MARKERS
+                .iter()
+                .filter_map(|marker| find(&buffer[start..OVERLAP_SIZE + bytes_read], marker))
+                .min();
+
+            // Checks the signature located at the marker.
+            if let Some(index) = index {
+                let offset =
+                    start_offset + (total_bytes_read + index + start - OVERLAP_SIZE) as u64;
+                reader.seek(SeekFrom::Start(offset))?;
+                let mut signature = [0; 16];
+                let bytes_read = reader.read(&mut signature)?;
+                return Ok(
+                    Self::from_signature(&signature[..bytes_read]).map(|(format, ..)| format)
+                );
+            }
+
+            // Rotates the buffer to the right by the overlap size.
+            buffer[..OVERLAP_SIZE + bytes_read].rotate_right(OVERLAP_SIZE);
+
+            // Updates the total bytes read.
+            total_bytes_read += bytes_read;
+        }
+
+        // No embedded archive was found.
+        Ok(None)
+    }
+
+    /// Determines the file format of the content of a compressed reader, decompressing only its
+    /// first bytes, if the compression format is supported.
+    pub(crate) fn from_inner_reader<R: Read + Seek>(
+        format: Self,
+        mut reader: R,
+    ) -> Result<Option<Self>> {
+        // Maximum number of decompressed bytes that can be processed by the reader (1 MB).
+        const READ_LIMIT: u64 = 1_048_576;
+
+        // Decodes the first bytes of Base64 text and determines their file format.
+        reader.rewind()?;
+        if format == Self::Base64 {
+            let mut text = Vec::new();
+            reader.take(READ_LIMIT).read_to_end(&mut text)?;
+            let bytes = decode_base64(&text);
+            return Ok((!bytes.is_empty()).then(|| Self::from_bytes(&bytes)));
+        }
+
+        // Creates a decoder for the compression format.
+        let decoder: Option<Box<dyn Read + '_>> = match format {
+            #[cfg(feature = "decompress-bzip2")]
+            Self::Bzip2 => Some(Box::new(bzip2::read::MultiBzDecoder::new(&mut reader))),
+            #[cfg(feature = "decompress-gzip")]
+            Self::CompressedTape | Self::Gzip => {
+                Some(Box::new(flate2::read::MultiGzDecoder::new(&mut reader)))
+            }
+            #[cfg(feature = "decompress-xz")]
+            Self::Xz => Some(Box::new(xz2::read::XzDecoder::new_multi_decoder(
+                &mut reader,
+            ))),
+            #[cfg(feature = "decompress-zstd")]
+            Self::Zstandard => ruzstd::StreamingDecoder::new(&mut reader)
+                .ok()
+                .map(|decoder| Box::new(decoder) as Box<dyn Read>),
+            _ => None,
+        };
+
+        // Decompresses the first bytes, keeping those decompressed before any error, and
+        // determines their file format.
+        Ok(decoder.and_then(|decoder| {
+            let mut bytes = Vec::new();
+            let result = decoder.take(READ_LIMIT).read_to_end(&mut bytes);
+            (result.is_ok() || !bytes.is_empty()).then(|| Self::from_bytes(&bytes))
+        }))
+    }
+
+    /// Determines file format from a gzip reader, inflating its first block to check for a tape
+    /// archive.
+    #[cfg(feature = "decompress-gzip")]
+    pub(crate) fn from_gzip_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Reads the first decompressed bytes, up to the end of the ustar magic.
+        reader.rewind()?;
+        let mut buffer = [0; 263];
+        let mut decoder = flate2::read::GzDecoder::new(reader);
+        let filled = decoder.read_exact(&mut buffer).is_ok();
+
+        // Checks the POSIX and GNU magic numbers of a tape archive.
+        let is_tape = filled && matches!(&buffer[257..], b"ustar\0" | b"ustar ");
+        Ok(if is_tape {
+            Self::CompressedTape
+        } else {
+            Self::Gzip
+        })
+    }
+
+    /// Determines file format from an Arrow reader, which is only recognized in the streaming
+    /// format by the framing of its first message.
+    #[cfg(feature = "reader-arrow")]
+    pub(crate) fn from_arrow_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Maximum size of a message that can be processed by the reader (1 MB).
+        const MESSAGE_SIZE_LIMIT: usize = 1_048_576;
+
+        // Reads the continuation marker and the size of the first message.
+        reader.rewind()?;
+        let mut prefix = [0; 8];
+        reader.read_exact(&mut prefix)?;
+        let size = i32::from_le_bytes([prefix[4], prefix[5], prefix[6], prefix[7]]);
+        if prefix[..4] != [0xFF; 4] || size <= 0 || size % 8 != 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "invalid Arrow message prefix",
+            ));
+        }
+        let size = size as usize;
+        if size > MESSAGE_SIZE_LIMIT {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Arrow message too large",
+            ));
+        }
+
+        // Reads the first message, which must hold the schema.
+        let mut message = vec![0; size];
+        reader.read_exact(&mut message)?;
+        if is_arrow_schema_message(&message) {
+            Ok(Self::ApacheArrowStream)
+        } else {
+            Err(Error::new(ErrorKind::InvalidData, "invalid Arrow message"))
+        }
+    }
+
     /// Determines file format from an ASF reader.
     #[cfg(feature = "reader-asf")]
     pub(crate) fn from_asf_reader<R: Read + Seek>(reader: R) -> Result<Self> {
@@ -180,14 +431,179 @@ impl crate::FileFormat {
         })
     }
 
+    /// Determines file format from an ASN.1 reader.
+    #[cfg(feature = "reader-asn1")]
+    pub(crate) fn from_asn1_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Maximum size of an element that can be processed by the reader.
+        const ELEMENT_LIMIT: u64 = 1_048_576;
+
+        // Gets the stream length.
+        let length = reader.seek(SeekFrom::End(0))?;
+        if length > ELEMENT_LIMIT {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "ASN.1 element too large",
+            ));
+        }
+
+        // Reads the identifier and the length octets, checking that the stream starts with a
+        // SEQUENCE or a SET whose definite length, if any, covers the whole stream.
+        reader.rewind()?;
+        let mut header = [0; 6];
+        reader.read_exact(&mut header[..2])?;
+        let invalid = || Error::new(ErrorKind::InvalidData, "invalid ASN.1 element");
+        if !matches!(header[0], 0x30 | 0x31) {
+            return Err(invalid());
+        }
+        let size = match header[1] {
+            0x80 => None,
+            length @ 0x00..=0x7F => Some(2 + length as u64),
+            size @ 0x81..=0x84 => {
+                let size = (size & 0x7F) as usize;
+                reader.read_exact(&mut header[2..2 + size])?;
+                let length = header[2..2 + size]
+                    .iter()
+                    .fold(0, |length, &byte| length << 8 | byte as u64);
+                Some(2 + size as u64 + length)
+            }
+            _ => return Err(invalid()),
+        };
+        if size.map_or(false, |size| size != length) {
+            return Err(invalid());
+        }
+
+        // Reads the stream.
+        reader.rewind()?;
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+
+        // Checks that the stream consists of a single SEQUENCE or SET.
+        if asn1_element_size(&data, 0) == Some(data.len()) {
+            Ok(Self::AbstractSyntaxNotationOne)
+        } else {
+            Err(Error::new(ErrorKind::InvalidData, "invalid ASN.1 element"))
+        }
+    }
+
+    /// Determines file format from a BSON reader.
+    #[cfg(feature = "reader-bson")]
+    pub(crate) fn from_bson_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Maximum size of a document.
+        const DOCUMENT_MAX_SIZE: u64 = 16_777_216;
+
+        // Minimum size of a document.
+        const DOCUMENT_MIN_SIZE: u64 = 5;
+
+        // Gets the stream length.
+        let length = reader.seek(SeekFrom::End(0))?;
+
+        // Reads the document size.
+        reader.rewind()?;
+        let mut size = [0; 4];
+        reader.read_exact(&mut size)?;
+        let size = u32::from_le_bytes(size) as u64;
+
+        // Checks the document size.
+        if !(DOCUMENT_MIN_SIZE..=DOCUMENT_MAX_SIZE.min(length)).contains(&size) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "invalid BSON document size",
+            ));
+        }
+
+        // Checks the size of the next document, if any.
+        if size < length {
+            reader.seek(SeekFrom::Start(size))?;
+            let mut next_size = [0; 4];
+            reader.read_exact(&mut next_size)?;
+            let next_size = u32::from_le_bytes(next_size) as u64;
+            if !(DOCUMENT_MIN_SIZE..=DOCUMENT_MAX_SIZE.min(length - size)).contains(&next_size) {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "invalid BSON document size",
+                ));
+            }
+        }
+
+        // Reads the document.
+        reader.rewind()?;
+        let mut document = vec![0; size as usize];
+        reader.read_exact(&mut document)?;
+
+        // Validates the document structure.
+        if is_bson_document(&document, 0) {
+            Ok(Self::BinaryJson)
+        } else {
+            Err(Error::new(ErrorKind::InvalidData, "invalid BSON document"))
+        }
+    }
+
+    /// Determines file format from a Cap'n Proto reader.
+    #[cfg(feature = "reader-capnp")]
+    pub(crate) fn from_capnp_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Maximum size of a message that can be processed by the reader.
+        const MESSAGE_LIMIT: u64 = 1_048_576;
+
+        // Size of the beginning of the stream holding the largest segment table and the root
+        // pointer of an unpacked message.
+        const HEADER_LIMIT: u64 = 4096;
+
+        // Gets the stream length.
+        let length = reader.seek(SeekFrom::End(0))?;
+
+        // Reads the beginning of the stream and checks the framing of an unpacked message.
+        reader.rewind()?;
+        let mut header = Vec::new();
+        reader
+            .by_ref()
+            .take(HEADER_LIMIT)
+            .read_to_end(&mut header)?;
+        if is_capnp_message(&header, length) {
+            return Ok(Self::CapnProto);
+        }
+        let invalid = || Error::new(ErrorKind::InvalidData, "invalid Cap'n Proto message");
+
+        // Checks the first word of a packed message before unpacking the whole stream.
+        if length > MESSAGE_LIMIT || !is_capnp_packed_message_start(&header) {
+            return Err(invalid());
+        }
+        reader.rewind()?;
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        if unpack_capnp_message(&data, MESSAGE_LIMIT as usize)
+            .map_or(false, |data| is_capnp_message(&data, data.len() as u64))
+        {
+            Ok(Self::CapnProto)
+        } else {
+            Err(invalid())
+        }
+    }
+
     /// Determines file format from a CFB reader.
     #[cfg(feature = "reader-cfb")]
     pub(crate) fn from_cfb_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
-        // UTF-16-encoded entry name for WPS file format (MatOST).
-        const WPS_ENTRY_NAME: &[u8] = b"M\0a\0t\0O\0S\0T\0";
-
-        // UTF-16-encoded entry name for XLR file format (WksSSWorkBook).
-        const XLR_ENTRY_NAME: &[u8] = b"W\0k\0s\0S\0S\0W\0o\0r\0k\0B\0o\0o\0k\0";
+        // Stream names identifying a file format, from the most specific to the least specific.
+        const STREAM_NAMES: &[(&str, crate::FileFormat)] = &[
+            (
+                "EncryptedPackage",
+                crate::FileFormat::EncryptedOfficeOpenXml,
+            ),
+            ("DestList", crate::FileFormat::WindowsJumpList),
+            ("MatOST", crate::FileFormat::MicrosoftWorksWordProcessor),
+            (
+                "WksSSWorkBook",
+                crate::FileFormat::MicrosoftWorks6Spreadsheet,
+            ),
+            ("Quill", crate::FileFormat::MicrosoftPublisherDocument),
+            ("VisioDocument", crate::FileFormat::MicrosoftVisioDrawing),
+            (
+                "PowerPoint Document",
+                crate::FileFormat::MicrosoftPowerpointPresentation,
+            ),
+            ("WordDocument", crate::FileFormat::MicrosoftWordDocument),
+            ("Workbook", crate::FileFormat::MicrosoftExcelSpreadsheet),
+            ("Book", crate::FileFormat::MicrosoftExcelSpreadsheet),
+        ];
 
         // Reads the major version.
         reader.seek(SeekFrom::Start(26))?;
@@ -268,29 +684,124 @@ impl crate::FileFormat {
             "519873ff-2dad-0220-1937-0000929679cd" => Self::WordperfectDocument,
             "402efe60-1999-101b-99ae-04021c007002" => Self::WordperfectGraphics,
             _ => {
-                // Reads the second directory entry name.
-                reader.seek(SeekFrom::Current(32))?;
-                let mut second_directory_entry_name = [0; 64];
-                reader.read_exact(&mut second_directory_entry_name)?;
-
-                // Checks the second directory entry name.
-                if second_directory_entry_name.starts_with(WPS_ENTRY_NAME) {
-                    return Ok(Self::MicrosoftWorksWordProcessor);
+                // Reads the directory entries.
+                let entries = cfb_directory_entries(
+                    &mut reader,
+                    directory_sector_size,
+                    first_directory_sector_location,
+                )?;
+
+                // Checks the signature of the HWP file header stream.
+                if let (Some(root_entry), Some(entry)) = (
+                    entries.first(),
+                    entries.iter().find(|entry| entry.name == "FileHeader"),
+                ) {
+                    let mut signature = [0; 17];
+                    if cfb_read_stream_start(
+                        &mut reader,
+                        directory_sector_size,
+                        root_entry,
+                        entry,
+                        &mut signature,
+                    )
+                    .is_ok()
+                        && &signature == b"HWP Document File"
+                    {
+                        return Ok(Self::HangulWordProcessor);
+                    }
                 }
 
-                // Reads the third directory entry name.
-                reader.seek(SeekFrom::Current(64))?;
-                let mut third_directory_entry_name = [0; 64];
-                reader.read_exact(&mut third_directory_entry_name)?;
+                // Falls back to the stream names when the CLSID is unknown.
+                STREAM_NAMES
+                    .iter()
+                    .find(|(name, _)| entries.iter().any(|entry| entry.name == *name))
+                    .map(|&(_, format)| format)
+                    .unwrap_or(Self::CompoundFileBinary)
+            }
+        })
+    }
+
+    /// Determines file format from a DXBC reader.
+    #[cfg(feature = "reader-dxbc")]
+    pub(crate) fn from_dxbc_reader<R: Read + Seek>(reader: R) -> Result<Self> {
+        // Maximum number of chunks that can be processed by the reader.
+        const CHUNK_LIMIT: u32 = 64;
 
-                // Checks the third directory entry name.
-                if third_directory_entry_name.starts_with(XLR_ENTRY_NAME) {
-                    return Ok(Self::MicrosoftWorks6Spreadsheet);
-                }
+        // Size of the header, without the chunk offsets.
+        const HEADER_SIZE: u64 = 32;
 
-                // Returns the default value.
-                Self::CompoundFileBinary
+        // Creates a buffered reader.
+        let mut reader = BufReader::new(reader);
+
+        // Gets the stream length.
+        let length = reader.seek(SeekFrom::End(0))?;
+
+        // Reads the total size.
+        reader.seek(SeekFrom::Start(24))?;
+        let mut total_size = [0; 4];
+        reader.read_exact(&mut total_size)?;
+        let total_size = u32::from_le_bytes(total_size) as u64;
+
+        // Reads the number of chunks.
+        let mut chunk_count = [0; 4];
+        reader.read_exact(&mut chunk_count)?;
+        let chunk_count = u32::from_le_bytes(chunk_count);
+
+        // Checks the total size and the number of chunks.
+        if total_size != length || chunk_count == 0 || chunk_count > CHUNK_LIMIT {
+            return Err(Error::new(ErrorKind::InvalidData, "invalid DXBC header"));
+        }
+
+        // Reads the chunk offsets.
+        let mut offsets = Vec::new();
+        for _ in 0..chunk_count {
+            let mut offset = [0; 4];
+            reader.read_exact(&mut offset)?;
+            offsets.push(u32::from_le_bytes(offset) as u64);
+        }
+
+        // Flag indicating the presence of a DXIL chunk.
+        let mut dxil = false;
+
+        // Iterates through the chunks.
+        for offset in offsets {
+            // Checks that the chunk header lies after the offset table.
+            if offset < HEADER_SIZE + 4 * chunk_count as u64 || offset + 8 > total_size {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "invalid DXBC chunk offset",
+                ));
+            }
+
+            // Reads the chunk type.
+            reader.seek(SeekFrom::Start(offset))?;
+            let mut chunk_type = [0; 4];
+            reader.read_exact(&mut chunk_type)?;
+
+            // Reads the chunk size.
+            let mut chunk_size = [0; 4];
+            reader.read_exact(&mut chunk_size)?;
+            let chunk_size = u32::from_le_bytes(chunk_size) as u64;
+
+            // Checks that the chunk data lies within the container.
+            if offset + 8 + chunk_size > total_size {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "invalid DXBC chunk size",
+                ));
+            }
+
+            // Checks the chunk type.
+            if &chunk_type == b"DXIL" {
+                dxil = true;
             }
+        }
+
+        // Determines the file format based on the identified chunks.
+        Ok(if dxil {
+            Self::DirectxIntermediateLanguage
+        } else {
+            Self::DirectxShaderBytecode
         })
     }
 
@@ -463,6 +974,12 @@ impl crate::FileFormat {
     /// Determines file format from an EXE reader.
     #[cfg(feature = "reader-exe")]
     pub(crate) fn from_exe_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Alignment of the NSIS header.
+        const NSIS_ALIGNMENT: u64 = 512;
+
+        // Signature of the NSIS header, located after its flags.
+        const NSIS_SIGNATURE: &[u8] = b"\xEF\xBE\xAD\xDENullsoftInst";
+
         // Gets the stream length.
         let length = reader.seek(SeekFrom::End(0))?;
 
@@ -489,11 +1006,23 @@ impl crate::FileFormat {
                 reader.read_exact(&mut characteristics)?;
 
                 // Checks the characteristics
-                return Ok(if u16::from_le_bytes(characteristics) & 0x2000 == 0x2000 {
-                    Self::DynamicLinkLibrary
-                } else {
-                    Self::PortableExecutable
-                });
+                if u16::from_le_bytes(characteristics) & 0x2000 == 0x2000 {
+                    return Ok(Self::DynamicLinkLibrary);
+                }
+
+                // Checks for the NSIS header in the first aligned block of the overlay.
+                if let Some(overlay_offset) = pe_overlay_offset(&mut reader) {
+                    let header_offset =
+                        (overlay_offset + NSIS_ALIGNMENT - 1) / NSIS_ALIGNMENT * NSIS_ALIGNMENT;
+                    reader.seek(SeekFrom::Start(header_offset + 4))?;
+                    let mut signature = [0; NSIS_SIGNATURE.len()];
+                    if reader.read_exact(&mut signature).is_ok() && signature == NSIS_SIGNATURE {
+                        return Ok(Self::NullsoftScriptableInstallSystem);
+                    }
+                }
+
+                // Returns the default value.
+                return Ok(Self::PortableExecutable);
             } else if &signature[..2] == b"LE" || &signature[..2] == b"LX" {
                 return Ok(Self::LinearExecutable);
             } else if &signature[..2] == b"NE" {
@@ -505,6 +1034,211 @@ impl crate::FileFormat {
         Ok(Self::MsDosExecutable)
     }
 
+    /// Determines file format from a FlatBuffers reader.
+    #[cfg(feature = "reader-flatbuffers")]
+    pub(crate) fn from_flatbuffers_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Maximum size of a buffer that can be processed by the reader.
+        const BUFFER_LIMIT: u64 = 1_048_576;
+
+        // Checks the root table offset against the stream length and the file identifier before
+        // reading the buffer.
+        let length = reader.seek(SeekFrom::End(0))?;
+        reader.rewind()?;
+        let mut header = [0; 8];
+        reader.read_exact(&mut header)?;
+        let root = u32::from_le_bytes([header[0], header[1], header[2], header[3]]) as u64;
+        if root < 8
+            || root % 4 != 0
+            || root + 4 > length.min(BUFFER_LIMIT)
+            || !header[4..].iter().all(u8::is_ascii_alphanumeric)
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "invalid FlatBuffers buffer",
+            ));
+        }
+
+        // Reads the beginning of the stream.
+        reader.rewind()?;
+        let mut data = Vec::new();
+        reader.take(BUFFER_LIMIT).read_to_end(&mut data)?;
+
+        // Checks the file identifier and the root table.
+        if is_flatbuffers_buffer(&data) {
+            Ok(Self::Flatbuffers)
+        } else {
+            Err(Error::new(
+                ErrorKind::InvalidData,
+                "invalid FlatBuffers buffer",
+            ))
+        }
+    }
+
+    /// Determines file format from a GB reader, checking the Nintendo logo and the header checksum
+    /// when the stream holds the whole cartridge header.
+    #[cfg(feature = "reader-gb")]
+    pub(crate) fn from_gb_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Nintendo logo displayed by the boot ROM, which refuses to start if it does not match.
+        const LOGO: &[u8; 48] = b"\xCE\xED\x66\x66\xCC\x0D\x00\x0B\x03\x73\x00\x83\x00\x0C\x00\x0D\
+            \x00\x08\x11\x1F\x88\x89\x00\x0E\xDC\xCC\x6E\xE6\xDD\xDD\xD9\x99\xBB\xBB\x67\x63\
+            \x6E\x0E\xEC\xCC\xDD\xDC\x99\x9F\xBB\xB9\x33\x3E";
+
+        // Size of the cartridge header, from the Nintendo logo to the header checksum.
+        const HEADER_SIZE: u64 = 74;
+
+        // Reads the cartridge header.
+        reader.seek(SeekFrom::Start(0x104))?;
+        let mut header = Vec::new();
+        reader.take(HEADER_SIZE).read_to_end(&mut header)?;
+
+        // Checks the Nintendo logo and the header checksum, computed from the title to the mask
+        // ROM version number, unless the header is truncated.
+        if header.len() as u64 == HEADER_SIZE {
+            let checksum = header[48..73].iter().fold(0u8, |checksum, &byte| {
+                checksum.wrapping_sub(byte).wrapping_sub(1)
+            });
+            if header[..48] != LOGO[..] {
+                return Err(Error::new(ErrorKind::InvalidData, "invalid GB logo"));
+            }
+            if checksum != header[73] {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "invalid GB header checksum",
+                ));
+            }
+        }
+
+        // Determines the file format based on the CGB flag.
+        Ok(match header.get(63) {
+            Some(flag) if flag & 0x80 == 0x80 => Self::GameBoyColorRom,
+            _ => Self::GameBoyRom,
+        })
+    }
+
+    /// Determines file format from an IFF reader, according to the form type.
+    #[cfg(feature = "reader-iff")]
+    pub(crate) fn from_iff_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Reads the header of the FORM chunk.
+        reader.rewind()?;
+        let mut header = [0; 12];
+        reader.read_exact(&mut header)?;
+        if &header[..4] != b"FORM" {
+            return Err(Error::new(ErrorKind::InvalidData, "invalid IFF header"));
+        }
+
+        // Checks that the chunk size does not exceed the stream length.
+        let size = u32::from_be_bytes(header[4..8].try_into().unwrap());
+        if size as u64 > reader.seek(SeekFrom::End(0))? {
+            return Err(Error::new(ErrorKind::InvalidData, "invalid IFF chunk size"));
+        }
+
+        // Checks that the form type is a valid identifier, made of uppercase letters, digits and
+        // trailing spaces.
+        let form_type = &header[8..12];
+        if !form_type
+            .iter()
+            .all(|&byte| byte.is_ascii_uppercase() || byte.is_ascii_digit() || byte == b' ')
+            || form_type[0] == b' '
+        {
+            return Err(Error::new(ErrorKind::InvalidData, "invalid IFF form type"));
+        }
+
+        // Determines the file format from the form type.
+        Ok(match form_type {
+            b"ANIM" => Self::IffAnimation,
+            b"ILBM" | b"PBM " => Self::InterleavedBitmap,
+            _ => Self::InterchangeFileFormat,
+        })
+    }
+
+    /// Determines file format from a JSON reader.
+    #[cfg(feature = "reader-json")]
+    pub(crate) fn from_json_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Maximum number of bytes that can be processed by the reader (64 KB).
+        const READ_LIMIT: u64 = 65_536;
+
+        // Reads the first bytes of the stream.
+        reader.rewind()?;
+        let mut buffer = Vec::new();
+        reader.take(READ_LIMIT).read_to_end(&mut buffer)?;
+        let data = buffer.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(&buffer);
+
+        // Checks for a compact JSON Web Token.
+        if is_json_web_token(data) {
+            return Ok(Self::JsonWebToken);
+        }
+
+        // Scans the members of the top-level object.
+        let truncated = buffer.len() as u64 == READ_LIMIT;
+        let members = scan_json_object(data, truncated)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "invalid JSON object"))?;
+        let kind = |path: &[u8]| {
+            members
+                .iter()
+                .find(|(member, _)| member == path)
+                .map(|(_, kind)| *kind)
+        };
+
+        // Determines the file format based on the identified members.
+        if kind(b"log") == Some(JsonKind::Object)
+            && kind(b"log/creator") == Some(JsonKind::Object)
+            && kind(b"log/entries") == Some(JsonKind::Array)
+        {
+            Ok(Self::HttpArchive)
+        } else if kind(b"keys") == Some(JsonKind::Array) && kind(b"keys/kty").is_some() {
+            Ok(Self::JsonWebKeySet)
+        } else {
+            Err(Error::new(ErrorKind::InvalidData, "unknown JSON object"))
+        }
+    }
+
+    /// Determines file format from a MIDI reader, identifying karaoke files by their lyrics.
+    #[cfg(feature = "reader-midi")]
+    pub(crate) fn from_midi_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Maximum number of tracks that can be processed by the reader.
+        const TRACK_LIMIT: usize = 4;
+
+        // Maximum size of a track that can be processed by the reader (64 KB).
+        const TRACK_SIZE_LIMIT: u64 = 65_536;
+
+        // Reads the header chunk, falling back to the base file format when it is invalid.
+        reader.rewind()?;
+        let mut header = [0; 8];
+        if reader.read_exact(&mut header).is_err() {
+            return Ok(Self::MusicalInstrumentDigitalInterface);
+        }
+        let header_size = u32::from_be_bytes([header[4], header[5], header[6], header[7]]);
+        if header_size < 6 {
+            return Ok(Self::MusicalInstrumentDigitalInterface);
+        }
+        reader.seek(SeekFrom::Current(header_size as i64))?;
+
+        // Scans the events of the first tracks for lyrics, ignoring truncated tracks.
+        for _ in 0..TRACK_LIMIT {
+            let mut chunk_header = [0; 8];
+            if reader.read_exact(&mut chunk_header).is_err() {
+                break;
+            }
+            let chunk_size = u32::from_be_bytes([
+                chunk_header[4],
+                chunk_header[5],
+                chunk_header[6],
+                chunk_header[7],
+            ]) as u64;
+            let mut data = Vec::new();
+            if &chunk_header[..4] == b"MTrk" {
+                (&mut reader)
+                    .take(chunk_size.min(TRACK_SIZE_LIMIT))
+                    .read_to_end(&mut data)?;
+                if midi_track_has_lyrics(&data) == Some(true) {
+                    return Ok(Self::MidiKaraoke);
+                }
+            }
+            reader.seek(SeekFrom::Current((chunk_size - data.len() as u64) as i64))?;
+        }
+        Ok(Self::MusicalInstrumentDigitalInterface)
+    }
+
     /// Determines file format from a MP4 reader.
     #[cfg(feature = "reader-mp4")]
     pub(crate) fn from_mp4_reader<R: Read + Seek>(reader: R) -> Result<Self> {
@@ -588,6 +1322,53 @@ impl crate::FileFormat {
         })
     }
 
+    /// Determines file format from an ORC reader, by checking the magic number at the start of the
+    /// file and within the postscript at its end.
+    #[cfg(feature = "reader-orc")]
+    pub(crate) fn from_orc_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Magic number at the start of the file.
+        const MAGIC: &[u8] = b"ORC";
+
+        // Magic number within the postscript, as a length-delimited field with number 8000.
+        const POSTSCRIPT_MAGIC: &[u8] = b"\x82\xF4\x03\x03ORC";
+
+        // Checks the magic number at the start of the file.
+        reader.rewind()?;
+        let mut magic = [0; MAGIC.len()];
+        reader.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "invalid ORC magic number",
+            ));
+        }
+
+        // Reads the postscript, whose size is stored in the last byte of the file.
+        let length = reader.seek(SeekFrom::End(0))?;
+        reader.seek(SeekFrom::End(-1))?;
+        let mut postscript_size = [0; 1];
+        reader.read_exact(&mut postscript_size)?;
+        let postscript_size = postscript_size[0] as u64;
+        if postscript_size < POSTSCRIPT_MAGIC.len() as u64
+            || postscript_size + 1 + MAGIC.len() as u64 > length
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "invalid ORC postscript size",
+            ));
+        }
+        reader.seek(SeekFrom::End(-1 - postscript_size as i64))?;
+        let mut postscript = vec![0; postscript_size as usize];
+        reader.read_exact(&mut postscript)?;
+
+        // Checks the magic number within the postscript.
+        if find(&postscript, POSTSCRIPT_MAGIC).is_some() {
+            Ok(Self::OptimizedRowColumnar)
+        } else {
+            Err(Error::new(ErrorKind::InvalidData, "invalid ORC postscript"))
+        }
+    }
+
     /// Determines file format from a PDF reader.
     #[cfg(feature = "reader-pdf")]
     pub(crate) fn from_pdf_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
@@ -717,35 +1498,330 @@ impl crate::FileFormat {
         })
     }
 
-    /// Determines file format from a TXT reader.
-    #[cfg(feature = "reader-txt")]
-    pub(crate) fn from_txt_reader<R: Read + Seek>(reader: R) -> Result<Self> {
-        // Maximum number of lines that the can be processed by the reader.
-        const LINE_LIMIT: usize = 16;
+    /// Determines file format from a SFC reader.
+    #[cfg(feature = "reader-sfc")]
+    pub(crate) fn from_sfc_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Offsets of the internal header for LoROM, HiROM and ExHiROM cartridges.
+        const HEADER_OFFSETS: [u64; 3] = [0x7FC0, 0xFFC0, 0x40FFC0];
 
-        // Maximum number of bytes that can be processed by the reader (64 KB).
-        const READ_LIMIT: u64 = 65_536;
+        // Size of the optional header added by copier devices.
+        const COPIER_HEADER_SIZE: u64 = 512;
+
+        // Gets the stream length.
+        let length = reader.seek(SeekFrom::End(0))?;
+
+        // Skips the copier header, if any.
+        let base = if length % 1024 == COPIER_HEADER_SIZE {
+            COPIER_HEADER_SIZE
+        } else {
+            0
+        };
+
+        // Iterates through the candidate internal headers.
+        for offset in HEADER_OFFSETS {
+            if base + offset + 32 > length {
+                break;
+            }
+
+            // Reads the internal header.
+            reader.seek(SeekFrom::Start(base + offset))?;
+            let mut header = [0; 32];
+            reader.read_exact(&mut header)?;
+
+            // Checks the internal header.
+            if is_sfc_header(&header) {
+                return Ok(Self::SuperNintendoRom);
+            }
+        }
+        Err(Error::new(ErrorKind::InvalidData, "invalid SFC header"))
+    }
+
+    /// Determines file format from a Squashfs reader.
+    ///
+    /// Only directory table metadata blocks stored uncompressed are inspected.
+    #[cfg(feature = "reader-squashfs")]
+    pub(crate) fn from_squashfs_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Maximum size of the directory table that can be processed by the reader.
+        const DIRECTORY_TABLE_LIMIT: u64 = 1_048_576;
+
+        // Flag indicating that a metadata block is stored uncompressed.
+        const UNCOMPRESSED_FLAG: u16 = 0x8000;
+
+        // Reads the major version.
+        reader.seek(SeekFrom::Start(28))?;
+        let mut major_version = [0; 2];
+        reader.read_exact(&mut major_version)?;
+        if u16::from_le_bytes(major_version) != 4 {
+            return Ok(Self::Squashfs);
+        }
+
+        // Reads the bytes used.
+        reader.seek(SeekFrom::Start(40))?;
+        let mut bytes_used = [0; 8];
+        reader.read_exact(&mut bytes_used)?;
+        let bytes_used = u64::from_le_bytes(bytes_used);
+
+        // Reads the offsets of the tables following the directory table.
+        let mut table_offsets = Vec::new();
+        for offset in [48, 56, 80, 88] {
+            reader.seek(SeekFrom::Start(offset))?;
+            let mut table_offset = [0; 8];
+            reader.read_exact(&mut table_offset)?;
+            table_offsets.push(u64::from_le_bytes(table_offset));
+        }
+
+        // Reads the directory table offset.
+        reader.seek(SeekFrom::Start(72))?;
+        let mut directory_table_offset = [0; 8];
+        reader.read_exact(&mut directory_table_offset)?;
+        let directory_table_offset = u64::from_le_bytes(directory_table_offset);
+
+        // Calculates the size of the directory table.
+        let directory_table_size = table_offsets
+            .into_iter()
+            .filter(|&offset| offset > directory_table_offset)
+            .fold(bytes_used, std::cmp::min)
+            .saturating_sub(directory_table_offset)
+            .min(DIRECTORY_TABLE_LIMIT);
+
+        // Reads the directory table.
+        reader.seek(SeekFrom::Start(directory_table_offset))?;
+        let mut directory_table = Vec::new();
+        reader
+            .take(directory_table_size)
+            .read_to_end(&mut directory_table)?;
+
+        // Collects the content of the uncompressed metadata blocks.
+        let mut directories = Vec::new();
+        let mut index = 0;
+        while index + 2 <= directory_table.len() {
+            // Reads the metadata block header.
+            let header = u16::from_le_bytes([directory_table[index], directory_table[index + 1]]);
+            let start = index + 2;
+            let end = std::cmp::min(
+                start + (header & !UNCOMPRESSED_FLAG) as usize,
+                directory_table.len(),
+            );
+
+            // Keeps the metadata block if it is stored uncompressed.
+            if header & UNCOMPRESSED_FLAG != 0 {
+                directories.extend_from_slice(&directory_table[start..end]);
+            }
+            index = end;
+        }
+
+        // Determines the file format based on the identified directory entries.
+        Ok(
+            if find(&directories, b"\x03\x00meta").is_some()
+                && find(&directories, b"\x08\x00snap.yaml").is_some()
+            {
+                Self::Snap
+            } else {
+                Self::Squashfs
+            },
+        )
+    }
+
+    /// Determines file format from a TAR reader.
+    #[cfg(feature = "reader-tar")]
+    pub(crate) fn from_tar_reader<R: Read + Seek>(reader: R) -> Result<Self> {
+        // Maximum number of entries that can be processed by the reader.
+        const ENTRY_LIMIT: usize = 1024;
+
+        // Size of a block.
+        const BLOCK_SIZE: u64 = 512;
 
         // Creates a buffered reader.
         let mut reader = BufReader::new(reader);
 
+        // Gets the stream length.
+        let length = reader.seek(SeekFrom::End(0))?;
+
         // Rewinds to the beginning of the stream.
         reader.rewind()?;
 
-        // Determines if the reader contains only ASCII/UTF-8-encoded text by checking the first
-        // lines for control characters other than whitespaces.
-        reader
-            .take(READ_LIMIT)
-            .lines()
-            .take(LINE_LIMIT)
-            .try_for_each(|line| {
-                line?
-                    .chars()
-                    .find(|char| char.is_control() && !char.is_whitespace())
-                    .map(|_| Err(Error::new(ErrorKind::InvalidData, "invalid characters")))
-                    .unwrap_or(Ok(()))
-            })
-            .map(|_| Self::PlainText)
+        // Flags indicating the presence of specific entries.
+        let mut docker_manifest = false;
+        let mut docker_repositories = false;
+        let mut gem_data = false;
+        let mut gem_metadata = false;
+        let mut oci_index = false;
+        let mut oci_layout = false;
+
+        // Iterates through the entries.
+        for _ in 0..ENTRY_LIMIT {
+            // Checks that a header can be read.
+            if reader.stream_position()? + BLOCK_SIZE > length {
+                break;
+            }
+
+            // Reads the header.
+            let mut header = [0; BLOCK_SIZE as usize];
+            reader.read_exact(&mut header)?;
+
+            // Checks for the end of archive.
+            if header[0] == 0 {
+                break;
+            }
+
+            // Reads the entry name.
+            let name = &header[..100];
+            let name = &name[..name
+                .iter()
+                .position(|&byte| byte == 0)
+                .unwrap_or(name.len())];
+
+            // Reads the entry size, which is octal-encoded or base-256-encoded when its high bit is
+            // set, stopping at the first entry whose size cannot be decoded.
+            let size = &header[124..136];
+            let size = if size[0] & 0x80 != 0 {
+                (size[..4] == [0x80, 0, 0, 0])
+                    .then(|| u64::from_be_bytes(size[4..].try_into().unwrap()))
+            } else {
+                size.iter()
+                    .filter(|&&byte| byte != 0 && byte != b' ')
+                    .try_fold(0u64, |size, &byte| match byte {
+                        b'0'..=b'7' => Some(size << 3 | (byte - b'0') as u64),
+                        _ => None,
+                    })
+            };
+            let size = match size {
+                Some(size) => size,
+                None => break,
+            };
+
+            // Checks the entry name.
+            match name.strip_prefix(b"./").unwrap_or(name) {
+                b"data.tar.gz" => gem_data = true,
+                b"index.json" => oci_index = true,
+                b"manifest.json" => docker_manifest = true,
+                b"metadata.gz" => gem_metadata = true,
+                b"oci-layout" => oci_layout = true,
+                b"repositories" => docker_repositories = true,
+                _ => {}
+            }
+
+            // Seeks to the next header, if within the stream.
+            if size > length {
+                break;
+            }
+            let padded_size = (size + BLOCK_SIZE - 1) / BLOCK_SIZE * BLOCK_SIZE;
+            reader.seek(SeekFrom::Current(padded_size as i64))?;
+        }
+
+        // Determines the file format based on the identified entries.
+        Ok(if docker_manifest && docker_repositories {
+            Self::DockerImage
+        } else if oci_layout && oci_index {
+            Self::OciImageLayout
+        } else if gem_metadata && gem_data {
+            Self::RubyGem
+        } else {
+            Self::TapeArchive
+        })
+    }
+
+    /// Determines file format from a TGA reader, which is only recognized by the footer of the
+    /// version 2 format.
+    #[cfg(feature = "reader-tga")]
+    pub(crate) fn from_tga_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Signature located at the end of the footer.
+        const FOOTER_SIGNATURE: &[u8] = b"TRUEVISION-XFILE.\0";
+
+        // Reads the signature of the footer.
+        reader.seek(SeekFrom::End(-(FOOTER_SIGNATURE.len() as i64)))?;
+        let mut signature = [0; FOOTER_SIGNATURE.len()];
+        reader.read_exact(&mut signature)?;
+        if signature != FOOTER_SIGNATURE {
+            return Err(Error::new(ErrorKind::InvalidData, "invalid TGA footer"));
+        }
+
+        // Checks the color map type and the image type of the header.
+        reader.rewind()?;
+        let mut header = [0; 3];
+        reader.read_exact(&mut header)?;
+        if header[1] <= 1 && matches!(header[2], 1 | 2 | 3 | 9 | 10 | 11) {
+            Ok(Self::TruevisionTga)
+        } else {
+            Err(Error::new(ErrorKind::InvalidData, "invalid TGA header"))
+        }
+    }
+
+    /// Determines file format from a Thrift reader.
+    #[cfg(feature = "reader-thrift")]
+    pub(crate) fn from_thrift_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Maximum size of a message header that can be processed by the reader.
+        const HEADER_LIMIT: u64 = 512;
+
+        // Reads the message header.
+        reader.rewind()?;
+        let mut header = Vec::new();
+        reader.take(HEADER_LIMIT).read_to_end(&mut header)?;
+
+        // Checks the message envelope and the first field header.
+        if is_thrift_message(&header) {
+            Ok(Self::Thrift)
+        } else {
+            Err(Error::new(ErrorKind::InvalidData, "invalid Thrift message"))
+        }
+    }
+
+    /// Determines file format from a TXT reader.
+    #[cfg(feature = "reader-txt")]
+    pub(crate) fn from_txt_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Maximum number of lines that the can be processed by the reader.
+        const LINE_LIMIT: usize = 16;
+
+        // Maximum number of bytes that can be processed by the reader (64 KB).
+        const READ_LIMIT: u64 = 65_536;
+
+        // Rewinds to the beginning of the stream.
+        reader.rewind()?;
+
+        // Reads the first bytes of the stream.
+        let mut buffer = Vec::new();
+        reader.take(READ_LIMIT).read_to_end(&mut buffer)?;
+
+        // Keeps only the first lines.
+        let length = buffer
+            .iter()
+            .enumerate()
+            .filter(|(_, &byte)| byte == b'\n')
+            .nth(LINE_LIMIT - 1)
+            .map(|(index, _)| index);
+
+        // Determines if the first lines contain text in a supported encoding.
+        let truncated = length.is_none() && buffer.len() as u64 == READ_LIMIT;
+        if guess_text_encoding(&buffer[..length.unwrap_or(buffer.len())], truncated).is_none() {
+            return Err(Error::new(ErrorKind::InvalidData, "invalid characters"));
+        }
+
+        // Determines the file format based on the content.
+        Ok(if is_context_diff(&buffer) {
+            Self::ContextDiff
+        } else if is_unified_diff(&buffer) {
+            Self::UnifiedDiff
+        } else if is_adobe_font_metrics(&buffer) {
+            Self::AdobeFontMetrics
+        } else if is_base64(&buffer) {
+            Self::Base64
+        } else if is_esri_ascii_grid(&buffer) {
+            Self::EsriAsciiGrid
+        } else if is_graphviz_dot(&buffer) {
+            Self::GraphvizDot
+        } else if is_projection_wkt(&buffer) {
+            Self::ProjectionWkt
+        } else if is_sql_script(&buffer) {
+            Self::SqlScript
+        } else if is_synchronized_accessible_media_interchange(&buffer) {
+            Self::SynchronizedAccessibleMediaInterchange
+        } else if is_world_file(&buffer) {
+            Self::WorldFile
+        } else if is_zeek_log(&buffer) {
+            Self::ZeekLog
+        } else {
+            Self::PlainText
+        })
     }
 
     /// Determines file format from a XML reader.
@@ -825,27 +1901,81 @@ impl crate::FileFormat {
         Ok(Self::ExtensibleMarkupLanguage)
     }
 
+    /// Determines file format from an XZ reader.
+    #[cfg(feature = "reader-xz")]
+    pub(crate) fn from_xz_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Size of the stream header and of the stream footer.
+        const HEADER_SIZE: u64 = 12;
+
+        // Maximum size of the stream padding that can be skipped by the reader.
+        const PADDING_LIMIT: u64 = 1024;
+
+        // Reads the stream flags from the stream header.
+        let flags = read_xz_stream_flags(&mut reader)?;
+
+        // Skips the stream padding, made of null bytes in multiples of four.
+        let length = reader.seek(SeekFrom::End(0))?;
+        let mut end = length;
+        let mut word = [0; 4];
+        while end >= 2 * HEADER_SIZE + 4 && length - end < PADDING_LIMIT {
+            reader.seek(SeekFrom::Start(end - 4))?;
+            reader.read_exact(&mut word)?;
+            if word != [0; 4] {
+                break;
+            }
+            end -= 4;
+        }
+        if end < 2 * HEADER_SIZE {
+            return Ok(Self::Xz);
+        }
+
+        // Reads the stream footer, which is only checked when its magic is present so that
+        // truncated streams are still recognized.
+        reader.seek(SeekFrom::Start(end - HEADER_SIZE))?;
+        let mut footer = [0; HEADER_SIZE as usize];
+        reader.read_exact(&mut footer)?;
+        if &footer[10..] == b"YZ"
+            && (footer[8..10] != flags
+                || crc32(&footer[4..10]) != u32::from_le_bytes(footer[..4].try_into().unwrap()))
+        {
+            return Err(Error::new(ErrorKind::InvalidData, "invalid stream footer"));
+        }
+        Ok(Self::Xz)
+    }
+
     /// Determines file format from a ZIP reader.
     #[cfg(feature = "reader-zip")]
     pub(crate) fn from_zip_reader<R: Read + Seek>(reader: R) -> Result<Self> {
         // Maximum number of entries that can be processed by the reader.
         const ENTRY_LIMIT: usize = 1024;
 
+        // Signature of the central directory file header.
+        const CD_SIGNATURE: &[u8] = b"PK\x01\x02";
+
         // Signature of the ZIP64 end of central directory locator.
         const EOCD64_LOCATOR_SIGNATURE: &[u8] = b"PK\x06\x07";
 
+        // Signature of the ZIP64 end of central directory record.
+        const EOCD64_SIGNATURE: &[u8] = b"PK\x06\x06";
+
         // Signature of the end of central directory record.
         const EOCD_SIGNATURE: &[u8] = b"PK\x05\x06";
 
         // Size of the ZIP64 end of central directory locator.
         const EOCD64_LOCATOR_SIZE: usize = 20;
 
+        // Size of the ZIP64 end of central directory record, without extensible data.
+        const EOCD64_SIZE: u64 = 56;
+
         // Maximum size of the end of central directory record.
         const EOCD_MAX_SIZE: usize = EOCD_MIN_SIZE + u16::MAX as usize;
 
         // Minimum size of the end of central directory record.
         const EOCD_MIN_SIZE: usize = 22;
 
+        // Maximum size of a mimetype entry that can be processed by the reader.
+        const MIMETYPE_SIZE_LIMIT: u64 = 256;
+
         // Creates a buffered reader.
         let mut reader = BufReader::new(reader);
 
@@ -877,28 +2007,61 @@ impl crate::FileFormat {
             }
         }
 
-        // Reads the number of entries and the start of central directory offset.
-        let (number_of_entries, socd_offset) = if zip64 {
+        // Reads the number of entries, the size and the start of central directory offset, along
+        // with the position of the record following the central directory.
+        let (number_of_entries, cd_size, socd_offset, cd_end) = if zip64 {
             // Reads the offset of the ZIP64 end of central directory record.
+            let eocd64_locator_offset = eocd_offset - EOCD64_LOCATOR_SIZE as u64;
             reader.seek(SeekFrom::Current(4))?;
             let mut eocd64_offset = [0; 8];
             reader.read_exact(&mut eocd64_offset)?;
             let eocd64_offset = u64::from_le_bytes(eocd64_offset);
 
+            // Locates the ZIP64 end of central directory record, which immediately precedes the
+            // locator when data has been prepended to the archive.
+            let mut eocd64_position = None;
+            for position in [
+                Some(eocd64_offset),
+                eocd64_locator_offset.checked_sub(EOCD64_SIZE),
+            ]
+            .into_iter()
+            .flatten()
+            {
+                reader.seek(SeekFrom::Start(position))?;
+                let mut signature = [0; 4];
+                reader.read_exact(&mut signature)?;
+                if signature == EOCD64_SIGNATURE {
+                    eocd64_position = Some(position);
+                    break;
+                }
+            }
+            let eocd64_position = eocd64_position.ok_or_else(|| {
+                Error::new(ErrorKind::InvalidData, "cannot find the ZIP64 EOCD record")
+            })?;
+
             // Reads the number of entries.
-            reader.seek(SeekFrom::Start(eocd64_offset + 32))?;
+            reader.seek(SeekFrom::Start(eocd64_position + 32))?;
             let mut number_of_entries = [0; 8];
             reader.read_exact(&mut number_of_entries)?;
             let number_of_entries = u64::from_le_bytes(number_of_entries);
 
+            // Reads the size of the central directory.
+            let mut cd_size = [0; 8];
+            reader.read_exact(&mut cd_size)?;
+            let cd_size = u64::from_le_bytes(cd_size);
+
             // Reads the start of central directory offset.
-            reader.seek(SeekFrom::Current(8))?;
             let mut socd_offset = [0; 8];
             reader.read_exact(&mut socd_offset)?;
             let socd_offset = u64::from_le_bytes(socd_offset);
 
             // Returns the result.
-            (number_of_entries as usize, socd_offset)
+            (
+                number_of_entries as usize,
+                cd_size,
+                socd_offset,
+                eocd64_position,
+            )
         } else {
             // Reads the number of entries.
             reader.seek(SeekFrom::Start(eocd_offset + 10))?;
@@ -906,26 +2069,54 @@ impl crate::FileFormat {
             reader.read_exact(&mut number_of_entries)?;
             let number_of_entries = u16::from_le_bytes(number_of_entries);
 
+            // Reads the size of the central directory.
+            let mut cd_size = [0; 4];
+            reader.read_exact(&mut cd_size)?;
+            let cd_size = u32::from_le_bytes(cd_size);
+
             // Reads the start of central directory offset.
-            reader.seek(SeekFrom::Current(4))?;
             let mut socd_offset = [0; 4];
             reader.read_exact(&mut socd_offset)?;
             let socd_offset = u32::from_le_bytes(socd_offset);
 
             // Returns the result.
-            (number_of_entries as usize, socd_offset as u64)
+            (
+                number_of_entries as usize,
+                cd_size as u64,
+                socd_offset as u64,
+                eocd_offset,
+            )
         };
 
+        // Computes the offset of the archive in the stream, which is not null when data has been
+        // prepended to the archive (e.g. a self-extractor stub), since the central directory
+        // immediately precedes the end of central directory record.
+        let base_offset = cd_end
+            .checked_sub(cd_size)
+            .and_then(|socd_position| socd_position.checked_sub(socd_offset))
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "invalid central directory"))?;
+
         // Seeks to the start of central directory.
-        reader.seek(SeekFrom::Start(socd_offset))?;
+        reader.seek(SeekFrom::Start(base_offset + socd_offset))?;
 
         // Sets the default value.
         let mut format = Self::Zip;
 
         // Browses central directory headers.
         for _ in 0..std::cmp::min(ENTRY_LIMIT, number_of_entries) {
-            // Reads the compressed size.
-            reader.seek(SeekFrom::Current(20))?;
+            // Checks the signature of the central directory header.
+            let mut signature = [0; 4];
+            reader.read_exact(&mut signature)?;
+            if signature != CD_SIGNATURE {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "invalid central directory header",
+                ));
+            }
+
+            // Reads the compressed size. Sizes are always read from the central directory, since
+            // the local file header holds zeros when the entry is followed by a data descriptor.
+            reader.seek(SeekFrom::Current(16))?;
             let mut compressed_size = [0; 4];
             reader.read_exact(&mut compressed_size)?;
             let compressed_size = u32::from_le_bytes(compressed_size);
@@ -961,6 +2152,18 @@ impl crate::FileFormat {
             reader.read_exact(&mut filename)?;
             let filename = String::from_utf8_lossy(&filename).to_string();
 
+            // Reads the extra field.
+            let mut extra_field = vec![0; extra_field_length as usize];
+            reader.read_exact(&mut extra_field)?;
+
+            // Gets the 64-bit sizes and offset from the ZIP64 extended information, if any.
+            let (compressed_size, uncompressed_size, offset) = zip64_extended_information(
+                &extra_field,
+                compressed_size,
+                uncompressed_size,
+                offset,
+            );
+
             // Checks the filename.
             match filename.as_str() {
                 "AndroidManifest.xml" => return Ok(Self::AndroidPackage),
@@ -973,9 +2176,18 @@ impl crate::FileFormat {
                 "WEB-INF/web.xml" => return Ok(Self::WebApplicationArchive),
                 "doc.kml" => return Ok(Self::KeyholeMarkupLanguageZipped),
                 "extension.vsixmanifest" => return Ok(Self::MicrosoftVisualStudioExtension),
-                "mimetype" if compressed_size == uncompressed_size => {
+                "mimetype"
+                    if compressed_size == uncompressed_size
+                        && compressed_size <= MIMETYPE_SIZE_LIMIT =>
+                {
                     // Seeks to the filename of the local file header.
-                    reader.seek(SeekFrom::Start(offset as u64 + 26))?;
+                    let position = base_offset
+                        .checked_add(offset)
+                        .and_then(|position| position.checked_add(26))
+                        .ok_or_else(|| {
+                            Error::new(ErrorKind::InvalidData, "invalid local file header offset")
+                        })?;
+                    reader.seek(SeekFrom::Start(position))?;
 
                     // Reads the filename length.
                     let mut filename_length = [0; 2];
@@ -1057,6 +2269,11 @@ impl crate::FileFormat {
                         return Ok(Self::Autodesk123d);
                     } else if filename.starts_with("circuitdiagram/") {
                         return Ok(Self::CircuitDiagramDocument);
+                    } else if (filename.starts_with("info-") || filename.starts_with("pkg-"))
+                        && filename.ends_with(".tar.zst")
+                        && !filename.contains('/')
+                    {
+                        return Ok(Self::CondaPackage);
                     } else if filename.starts_with("dwf/") {
                         return Ok(Self::DesignWebFormatXps);
                     } else if filename.ends_with(".fb2") && !filename.contains('/') {
@@ -1073,6 +2290,12 @@ impl crate::FileFormat {
                         return Ok(Self::OfficeOpenXmlPresentation);
                     } else if filename.starts_with("xl/") {
                         return Ok(Self::OfficeOpenXmlSpreadsheet);
+                    } else if filename.starts_with("EGG-INFO/") {
+                        return Ok(Self::PythonEgg);
+                    } else if filename.ends_with(".dist-info/WHEEL")
+                        && filename.matches('/').count() == 1
+                    {
+                        return Ok(Self::PythonWheel);
                     } else if filename.starts_with("SpaceClaim/") {
                         return Ok(Self::SpaceclaimDocument);
                     } else if filename.starts_with("3D/") && filename.ends_with(".model") {
@@ -1088,16 +2311,49 @@ impl crate::FileFormat {
             }
 
             // Seeks to the next central directory entry.
-            reader.seek(SeekFrom::Current(
-                extra_field_length as i64 + file_comment_length as i64,
-            ))?;
+            reader.seek(SeekFrom::Current(file_comment_length as i64))?;
         }
         Ok(format)
     }
+
+    /// Determines file format from a Zstandard reader, looking past the skippable frames, which
+    /// are shared with LZ4.
+    #[cfg(feature = "reader-zstd")]
+    pub(crate) fn from_zstd_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Maximum number of skippable frames that can be processed by the reader.
+        const FRAME_LIMIT: usize = 16;
+
+        reader.rewind()?;
+        for _ in 0..FRAME_LIMIT {
+            // Reads the magic number of the frame, a stream made of skippable frames only being
+            // considered as Zstandard.
+            let mut magic_number = [0; 4];
+            match reader.read_exact(&mut magic_number) {
+                Ok(()) => {}
+                Err(error) if error.kind() == ErrorKind::UnexpectedEof => {
+                    return Ok(Self::Zstandard)
+                }
+                Err(error) => return Err(error),
+            }
+
+            // Checks the magic number, skipping the skippable frames.
+            match u32::from_le_bytes(magic_number) {
+                0x184C2102 => return Ok(Self::Lz4Legacy),
+                0x184D2204 => return Ok(Self::Lz4),
+                0x184D2A50..=0x184D2A5F => {
+                    let mut frame_size = [0; 4];
+                    reader.read_exact(&mut frame_size)?;
+                    reader.seek(SeekFrom::Current(u32::from_le_bytes(frame_size) as i64))?;
+                }
+                0xFD2FB528 => return Ok(Self::Zstandard),
+                _ => break,
+            }
+        }
+        Err(Error::new(ErrorKind::InvalidData, "unknown frame"))
+    }
 }
 
 /// Finds the first occurrence of a target sequence in a data array.
-#[cfg(any(feature = "reader-pdf", feature = "reader-zip"))]
 pub fn find(data: &[u8], target: &[u8]) -> Option<usize> {
     // An empty target sequence is always considered to be contained in the data.
     if target.is_empty() {
@@ -1129,3 +2385,2087 @@ pub fn find(data: &[u8], target: &[u8]) -> Option<usize> {
     }
     None
 }
+
+/// Gets the offset of the overlay of a PE reader, which is the data appended after its headers
+/// and its sections, or `None` if the reader is not a complete PE.
+fn pe_overlay_offset<R: Read + Seek>(mut reader: R) -> Option<u64> {
+    // Reads the extended header address.
+    reader.seek(SeekFrom::Start(60)).ok()?;
+    let mut offset = [0; 4];
+    reader.read_exact(&mut offset).ok()?;
+    let offset = u32::from_le_bytes(offset) as u64;
+
+    // Reads the signature and the COFF file header.
+    reader.seek(SeekFrom::Start(offset)).ok()?;
+    let mut header = [0; 24];
+    reader.read_exact(&mut header).ok()?;
+    if &header[..4] != b"PE\0\0" {
+        return None;
+    }
+    let number_of_sections = u16::from_le_bytes([header[6], header[7]]) as u64;
+    let optional_header_size = u16::from_le_bytes([header[20], header[21]]) as u64;
+
+    // Browses the section table, the overlay starting after the farthest section data.
+    let section_table_offset = offset + 24 + optional_header_size;
+    reader.seek(SeekFrom::Start(section_table_offset)).ok()?;
+    let mut overlay_offset = section_table_offset + 40 * number_of_sections;
+    let mut section = [0; 40];
+    for _ in 0..number_of_sections {
+        reader.read_exact(&mut section).ok()?;
+        let raw_data_size = u32::from_le_bytes(section[16..20].try_into().unwrap()) as u64;
+        let raw_data_pointer = u32::from_le_bytes(section[20..24].try_into().unwrap()) as u64;
+        overlay_offset = overlay_offset.max(raw_data_pointer + raw_data_size);
+    }
+    Some(overlay_offset)
+}
+
+/// Determines if the data looks like text using a quick heuristic: the first bytes must not contain
+/// any NUL byte and must be mostly made of printable characters or whitespaces.
+pub fn is_probably_text(data: &[u8]) -> bool {
+    // Maximum number of bytes that can be processed by the heuristic.
+    const SAMPLE_LIMIT: usize = 1024;
+
+    // Maximum percentage of non-printable bytes tolerated in the sample.
+    const NON_PRINTABLE_PERCENTAGE_LIMIT: usize = 5;
+
+    // Counts the non-printable bytes, rejecting the data as soon as a NUL byte is found.
+    let sample = &data[..std::cmp::min(SAMPLE_LIMIT, data.len())];
+    let mut non_printable_count = 0;
+    for &byte in sample {
+        match byte {
+            0x00 => return false,
+            b'\t' | b'\n' | b'\x0C' | b'\r' | b'\x1B' => {}
+            0x01..=0x1F | 0x7F => non_printable_count += 1,
+            _ => {}
+        }
+    }
+    !sample.is_empty() && non_printable_count * 100 <= sample.len() * NON_PRINTABLE_PERCENTAGE_LIMIT
+}
+
+/// Guesses the encoding of the text contained in the data, if any.
+///
+/// The data is first decoded as UTF-8, an incomplete character at the end being tolerated if the
+/// data is truncated. On failure, it is assumed to be encoded in a single-byte encoding: bytes in
+/// the `0x80..=0x9F` range are control characters in Latin-1 but printable ones in Windows-1252,
+/// so their presence indicates Windows-1252. In any case, control characters other than
+/// whitespaces are rejected.
+pub fn guess_text_encoding(data: &[u8], truncated: bool) -> Option<crate::TextEncoding> {
+    // Decodes the data as UTF-8, ignoring an incomplete character at the end of truncated data.
+    let text = match std::str::from_utf8(data) {
+        Ok(text) => Some(text),
+        Err(error) if truncated && error.error_len().is_none() => {
+            std::str::from_utf8(&data[..error.valid_up_to()]).ok()
+        }
+        Err(_) => None,
+    };
+
+    // Checks for control characters other than whitespaces.
+    if let Some(text) = text {
+        return if text
+            .chars()
+            .any(|char| char.is_control() && !char.is_whitespace())
+        {
+            None
+        } else {
+            Some(crate::TextEncoding::Utf8)
+        };
+    }
+
+    // Checks the bytes as a single-byte encoding.
+    let mut windows_1252 = false;
+    for &byte in data {
+        match byte {
+            b'\t' | b'\n' | b'\x0B' | b'\x0C' | b'\r' => {}
+            0x00..=0x1F | 0x7F | 0x81 | 0x8D | 0x8F | 0x90 | 0x9D => return None,
+            0x80..=0x9F => windows_1252 = true,
+            _ => {}
+        }
+    }
+    Some(if windows_1252 {
+        crate::TextEncoding::Windows1252
+    } else {
+        crate::TextEncoding::Latin1
+    })
+}
+
+/// Decodes Base64 text, skipping the armor and whitespaces, and stopping at the padding or at
+/// the first character outside of the standard and URL-safe alphabets.
+fn decode_base64(text: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(text.len() / 4 * 3);
+    let (mut bits, mut count) = (0u32, 0);
+    for line in text.split(|&byte| byte == b'\n') {
+        if line.starts_with(b"-----") {
+            continue;
+        }
+        for &byte in line {
+            let value = match byte {
+                b'A'..=b'Z' => byte - b'A',
+                b'a'..=b'z' => byte - b'a' + 26,
+                b'0'..=b'9' => byte - b'0' + 52,
+                b'+' | b'-' => 62,
+                b'/' | b'_' => 63,
+                b' ' | b'\t' | b'\r' => continue,
+                _ => return bytes,
+            };
+            bits = bits << 6 | value as u32;
+            count += 6;
+            if count >= 8 {
+                count -= 8;
+                bytes.push((bits >> count) as u8);
+            }
+        }
+    }
+    bytes
+}
+
+/// A buffer holding the first bytes of a stream, of which only some ranges might have been read.
+///
+/// It allows checking the signatures without reading more bytes than needed to tell them apart.
+pub struct PartialBuffer<'a> {
+    bytes: &'a mut [u8],
+    ranges: Vec<(usize, usize)>,
+    length: Option<usize>,
+    complete: bool,
+}
+
+impl<'a> PartialBuffer<'a> {
+    /// Default size of the buffer, which covers the farthest signature.
+    pub const SIZE: usize = 36870;
+
+    /// Number of bytes to read first, which covers most signatures.
+    pub const HEAD_SIZE: usize = 512;
+
+    /// Creates an empty buffer over the specified bytes, which must hold at least one byte so that
+    /// an empty stream can be told apart.
+    pub fn new(bytes: &'a mut [u8]) -> Self {
+        Self {
+            bytes,
+            ranges: Vec::new(),
+            length: None,
+            complete: false,
+        }
+    }
+
+    /// Returns the size of the buffer.
+    pub fn capacity(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Forgets the bytes read so far.
+    fn clear(&mut self) {
+        self.ranges.clear();
+        self.length = None;
+        self.complete = false;
+    }
+
+    /// Reads a range of the stream into the buffer, noting the stream length if it ends before.
+    pub fn read<R: Read + Seek>(&mut self, reader: &mut R, start: usize, end: usize) -> Result<()> {
+        let end = end.min(self.capacity());
+        reader.seek(SeekFrom::Start(start as u64))?;
+        let mut index = start;
+        while index < end {
+            match reader.read(&mut self.bytes[index..end]) {
+                Ok(0) => {
+                    self.length = Some(self.length.map_or(index, |length| length.min(index)));
+                    break;
+                }
+                Ok(bytes_read) => index += bytes_read,
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
+            }
+        }
+        self.ranges.push((start, index));
+        Ok(())
+    }
+
+    /// Reads the whole buffer, if not already done, and returns the bytes of the stream it holds.
+    pub fn fill<R: Read + Seek>(&mut self, reader: &mut R) -> Result<&[u8]> {
+        if !self.complete {
+            self.clear();
+            self.read(reader, 0, self.capacity())?;
+            self.complete = true;
+        }
+        Ok(&self.bytes[..self.length.unwrap_or(self.bytes.len())])
+    }
+
+    /// Checks whether the stream is empty.
+    pub fn is_empty(&self) -> bool {
+        self.length == Some(0)
+    }
+
+    /// Checks whether a value is present at an offset of the stream.
+    ///
+    /// Returns the range of bytes to read when the bytes read so far are not enough to tell.
+    pub fn check(&self, offset: usize, value: &[u8]) -> std::result::Result<bool, (usize, usize)> {
+        let end = offset + value.len();
+        if end > self.length.unwrap_or(self.bytes.len()) {
+            return Ok(false);
+        }
+        let mut known = false;
+        for &(start, stop) in &self.ranges {
+            let (from, to) = (start.max(offset), stop.min(end));
+            if from < to && self.bytes[from..to] != value[from - offset..to - offset] {
+                return Ok(false);
+            }
+            known |= start <= offset && end <= stop;
+        }
+        if known {
+            Ok(true)
+        } else {
+            Err((offset, end))
+        }
+    }
+}
+
+/// Details about the content of a file, gathered by the readers.
+#[derive(Default)]
+pub struct Details {
+    pub activity: Option<String>,
+    pub byte_order: Option<crate::ByteOrder>,
+    pub compression_method: Option<crate::CompressionMethod>,
+    pub encrypted: bool,
+    pub integrity_check: Option<crate::IntegrityCheck>,
+    pub link_type: Option<u16>,
+    pub variable: bool,
+    pub version: Option<String>,
+}
+
+impl Details {
+    /// Gathers details about the content of a file of the specified format, if any.
+    #[allow(unused_variables)]
+    pub fn from_reader<R: Read + Seek>(format: crate::FileFormat, reader: R) -> Self {
+        match format {
+            #[cfg(feature = "reader-7z")]
+            crate::FileFormat::SevenZip => Self::from_7z_reader(reader).unwrap_or_default(),
+            crate::FileFormat::Opentype
+            | crate::FileFormat::Truetype
+            | crate::FileFormat::TruetypeCollection => {
+                Self::from_sfnt_reader(reader).unwrap_or_default()
+            }
+            crate::FileFormat::PcapDump => Self::from_pcap_reader(reader).unwrap_or_default(),
+            crate::FileFormat::PcapNextGenerationDump => {
+                Self::from_pcapng_reader(reader).unwrap_or_default()
+            }
+            #[cfg(feature = "reader-xml")]
+            crate::FileFormat::GpsExchangeFormat
+            | crate::FileFormat::KeyholeMarkupLanguage
+            | crate::FileFormat::TrainingCenterXml => {
+                Self::from_xml_reader(format, reader).unwrap_or_default()
+            }
+            #[cfg(feature = "reader-xz")]
+            crate::FileFormat::Xz => Self::from_xz_reader(reader).unwrap_or_default(),
+            _ => Self::default(),
+        }
+    }
+
+    /// Gathers details from a 7z reader, by parsing the header or the description of the packed
+    /// header.
+    #[cfg(feature = "reader-7z")]
+    fn from_7z_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Maximum size of a header that can be processed by the reader (1 MB).
+        const HEADER_LIMIT: u64 = 1_048_576;
+
+        // Property IDs.
+        const HEADER: u8 = 0x01;
+        const ARCHIVE_PROPERTIES: u8 = 0x02;
+        const MAIN_STREAMS_INFO: u8 = 0x04;
+        const ENCODED_HEADER: u8 = 0x17;
+
+        // Reads the location of the next header from the start header.
+        reader.seek(SeekFrom::Start(12))?;
+        let mut start_header = [0; 16];
+        reader.read_exact(&mut start_header)?;
+        let next_header_offset = u64::from_le_bytes(start_header[..8].try_into().unwrap());
+        let next_header_size = u64::from_le_bytes(start_header[8..].try_into().unwrap());
+        if next_header_size == 0 || next_header_size > HEADER_LIMIT {
+            return Err(Error::new(ErrorKind::InvalidData, "invalid header size"));
+        }
+
+        // Reads the next header.
+        let next_header_position = 32u64
+            .checked_add(next_header_offset)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "invalid header offset"))?;
+        reader.seek(SeekFrom::Start(next_header_position))?;
+        let mut header = vec![0; next_header_size as usize];
+        reader.read_exact(&mut header)?;
+
+        // Gets the coders of the folders describing the content, skipping the archive properties.
+        let invalid = || Error::new(ErrorKind::InvalidData, "invalid header");
+        let mut data = &header[..];
+        let (&id, rest) = data.split_first().ok_or_else(invalid)?;
+        data = rest;
+        let coders = match id {
+            HEADER => {
+                if data.first() == Some(&ARCHIVE_PROPERTIES) {
+                    data = &data[1..];
+                    loop {
+                        let (&property_type, rest) = data.split_first().ok_or_else(invalid)?;
+                        data = rest;
+                        if property_type == 0 {
+                            break;
+                        }
+                        let size = read_7z_number(&mut data).ok_or_else(invalid)?;
+                        data = usize::try_from(size)
+                            .ok()
+                            .and_then(|size| data.get(size..))
+                            .ok_or_else(invalid)?;
+                    }
+                }
+                if data.first() != Some(&MAIN_STREAMS_INFO) {
+                    return Ok(Self::default());
+                }
+                read_7z_streams_info_coders(&data[1..]).ok_or_else(invalid)?
+            }
+            ENCODED_HEADER => read_7z_streams_info_coders(data).ok_or_else(invalid)?,
+            _ => return Err(invalid()),
+        };
+
+        // Looks for an encryption coder and the main compression method.
+        let encrypted = coders.iter().any(|coder| coder == b"\x06\xF1\x07\x01");
+        let methods: Vec<_> = coders
+            .iter()
+            .filter_map(|coder| match &coder[..] {
+                b"\x00" => Some(crate::CompressionMethod::Copy),
+                b"\x03\x01\x01" => Some(crate::CompressionMethod::Lzma),
+                b"\x03\x03\x01\x03" | b"\x04" => Some(crate::CompressionMethod::Bcj),
+                b"\x03\x03\x01\x1B" => Some(crate::CompressionMethod::Bcj2),
+                b"\x03\x04\x01" => Some(crate::CompressionMethod::Ppmd),
+                b"\x04\x01\x08" => Some(crate::CompressionMethod::Deflate),
+                b"\x04\x02\x02" => Some(crate::CompressionMethod::Bzip2),
+                b"\x21" => Some(crate::CompressionMethod::Lzma2),
+                _ => None,
+            })
+            .collect();
+        let compression_method = if id == ENCODED_HEADER {
+            // The coders only describe how the header itself is packed.
+            None
+        } else {
+            methods
+                .iter()
+                .find(|method| {
+                    !matches!(
+                        method,
+                        crate::CompressionMethod::Bcj | crate::CompressionMethod::Bcj2
+                    )
+                })
+                .or_else(|| methods.first())
+                .copied()
+        };
+        Ok(Self {
+            compression_method,
+            encrypted,
+            ..Self::default()
+        })
+    }
+
+    /// Gathers details from a PCAP reader, by reading the byte order from the magic number and the
+    /// link-layer type from the global header.
+    fn from_pcap_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        reader.rewind()?;
+        let mut header = [0; 24];
+        reader.read_exact(&mut header)?;
+        let byte_order = match header[..4] {
+            [0xA1, 0xB2, 0xC3, 0xD4] | [0xA1, 0xB2, 0x3C, 0x4D] => crate::ByteOrder::BigEndian,
+            [0xD4, 0xC3, 0xB2, 0xA1] | [0x4D, 0x3C, 0xB2, 0xA1] => crate::ByteOrder::LittleEndian,
+            _ => return Err(Error::new(ErrorKind::InvalidData, "invalid PCAP magic")),
+        };
+
+        // The link-layer type is held by the lower 16 bits, the upper ones describing the FCS.
+        let link_type = match byte_order {
+            crate::ByteOrder::BigEndian => u16::from_be_bytes([header[22], header[23]]),
+            crate::ByteOrder::LittleEndian => u16::from_le_bytes([header[20], header[21]]),
+        };
+        Ok(Self {
+            byte_order: Some(byte_order),
+            link_type: Some(link_type),
+            ..Self::default()
+        })
+    }
+
+    /// Gathers details from a PCAPNG reader, by reading the byte order from the section header
+    /// block and the link-layer type from the interface description block following it, if any.
+    fn from_pcapng_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Block type of an interface description block.
+        const INTERFACE_DESCRIPTION: u32 = 0x01;
+
+        // Reads the section header block up to its byte-order magic.
+        reader.rewind()?;
+        let mut header = [0; 12];
+        reader.read_exact(&mut header)?;
+        let byte_order = match header[8..] {
+            [0x1A, 0x2B, 0x3C, 0x4D] => crate::ByteOrder::BigEndian,
+            [0x4D, 0x3C, 0x2B, 0x1A] => crate::ByteOrder::LittleEndian,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "invalid PCAPNG byte-order magic",
+                ))
+            }
+        };
+        let read_u32 = |bytes: &[u8]| match byte_order {
+            crate::ByteOrder::BigEndian => {
+                u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+            }
+            crate::ByteOrder::LittleEndian => {
+                u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+            }
+        };
+
+        // Reads the link-layer type of the next block, if it describes an interface.
+        let mut link_type = None;
+        reader.seek(SeekFrom::Start(read_u32(&header[4..]) as u64))?;
+        let mut block = [0; 10];
+        if reader.read_exact(&mut block).is_ok() && read_u32(&block) == INTERFACE_DESCRIPTION {
+            link_type = Some(match byte_order {
+                crate::ByteOrder::BigEndian => u16::from_be_bytes([block[8], block[9]]),
+                crate::ByteOrder::LittleEndian => u16::from_le_bytes([block[8], block[9]]),
+            });
+        }
+        Ok(Self {
+            byte_order: Some(byte_order),
+            link_type,
+            ..Self::default()
+        })
+    }
+
+    /// Gathers details from an SFNT reader, by looking for the font variations table in the table
+    /// directory of the font or, for a collection, of its first font.
+    fn from_sfnt_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        // Maximum number of tables that can be processed by the reader.
+        const TABLE_LIMIT: usize = 256;
+
+        // Locates the table directory of the first font of a collection.
+        reader.rewind()?;
+        let mut header = [0; 16];
+        reader.read_exact(&mut header)?;
+        if &header[..4] == b"ttcf" {
+            let offset = u32::from_be_bytes([header[12], header[13], header[14], header[15]]);
+            reader.seek(SeekFrom::Start(offset as u64))?;
+            reader.read_exact(&mut header)?;
+        }
+
+        // Reads the tags of the table records.
+        let count = (u16::from_be_bytes([header[4], header[5]]) as usize).min(TABLE_LIMIT);
+        reader.seek(SeekFrom::Current(-4))?;
+        let mut records = vec![0; count * 16];
+        reader.read_exact(&mut records)?;
+        Ok(Self {
+            variable: records
+                .chunks_exact(16)
+                .any(|record| &record[..4] == b"fvar"),
+            ..Self::default()
+        })
+    }
+
+    /// Gathers details from an XML reader, by reading the version from the root element and, for
+    /// TCX, the sport of the first activity.
+    #[cfg(feature = "reader-xml")]
+    fn from_xml_reader<R: Read + Seek>(format: crate::FileFormat, mut reader: R) -> Result<Self> {
+        // Maximum number of bytes that can be processed by the reader (32 KB).
+        const READ_LIMIT: u64 = 32_768;
+
+        // Reads the first bytes.
+        reader.rewind()?;
+        let mut buffer = Vec::new();
+        reader.take(READ_LIMIT).read_to_end(&mut buffer)?;
+        let text = String::from_utf8_lossy(&buffer);
+
+        // Reads the version from the root element, either as an attribute or as the last part of
+        // the namespace.
+        let root = match format {
+            crate::FileFormat::GpsExchangeFormat => "gpx",
+            crate::FileFormat::KeyholeMarkupLanguage => "kml",
+            _ => "TrainingCenterDatabase",
+        };
+        let root_tag = xml_start_tag(&text, root);
+        let version = root_tag.and_then(|tag| match format {
+            crate::FileFormat::GpsExchangeFormat => xml_attribute(tag, "version"),
+            _ => xml_attribute(tag, "xmlns")
+                .and_then(|namespace| namespace.trim_end_matches('/').rsplit('/').next())
+                .map(|version| version.trim_start_matches('v')),
+        });
+
+        // Reads the sport of the first activity of a TCX file.
+        let activity = if format == crate::FileFormat::TrainingCenterXml {
+            xml_start_tag(&text, "Activity").and_then(|tag| xml_attribute(tag, "Sport"))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            activity: activity.map(String::from),
+            version: version
+                .filter(|version| !version.is_empty())
+                .map(String::from),
+            ..Self::default()
+        })
+    }
+
+    /// Gathers details from an XZ reader, by reading the check type from the stream flags.
+    #[cfg(feature = "reader-xz")]
+    fn from_xz_reader<R: Read + Seek>(mut reader: R) -> Result<Self> {
+        let flags = read_xz_stream_flags(&mut reader)?;
+        Ok(Self {
+            integrity_check: match flags[1] {
+                0x01 => Some(crate::IntegrityCheck::Crc32),
+                0x04 => Some(crate::IntegrityCheck::Crc64),
+                0x0A => Some(crate::IntegrityCheck::Sha256),
+                _ => None,
+            },
+            ..Self::default()
+        })
+    }
+}
+
+/// Checks whether a data array is an Arrow message holding a schema, by reading the version and the
+/// header type from its FlatBuffers table.
+#[cfg(feature = "reader-arrow")]
+fn is_arrow_schema_message(data: &[u8]) -> bool {
+    // Latest metadata version.
+    const VERSION_MAX: i16 = 4;
+
+    // Header type of a schema.
+    const SCHEMA: u8 = 1;
+
+    // Reads little-endian integers at the specified index.
+    let read_u16 = |index: usize| {
+        data.get(index..index + 2)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]) as usize)
+    };
+    let read_u32 = |index: usize| {
+        data.get(index..index + 4)
+            .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    };
+
+    // Locates the root table and its vtable.
+    let table = match read_u32(0) {
+        Some(offset) => offset as usize,
+        None => return false,
+    };
+    let vtable = match read_u32(table).map(|offset| table as i64 - offset as i32 as i64) {
+        Some(vtable) if vtable >= 0 => vtable as usize,
+        _ => return false,
+    };
+    let vtable_size = match read_u16(vtable) {
+        Some(size) if size >= 8 && size % 2 == 0 => size,
+        _ => return false,
+    };
+
+    // Reads the version and the header type fields, which have default values when absent.
+    let field = |index: usize| {
+        (4 + 2 * index < vtable_size)
+            .then(|| read_u16(vtable + 4 + 2 * index))
+            .flatten()
+            .filter(|&offset| offset != 0)
+            .map(|offset| table + offset)
+    };
+    let version = match field(0) {
+        Some(index) => match data.get(index..index + 2) {
+            Some(bytes) => i16::from_le_bytes([bytes[0], bytes[1]]),
+            None => return false,
+        },
+        None => 0,
+    };
+    let header_type = match field(1) {
+        Some(index) => data.get(index).copied(),
+        None => Some(0),
+    };
+    (0..=VERSION_MAX).contains(&version) && header_type == Some(SCHEMA)
+}
+
+/// Checks whether a data array is a well-formed BSON document.
+#[cfg(feature = "reader-bson")]
+fn is_bson_document(data: &[u8], depth: usize) -> bool {
+    // Maximum nesting depth of the documents.
+    const DEPTH_LIMIT: usize = 32;
+
+    // Reads a little-endian size at the specified index.
+    let read_size = |index: usize| {
+        data.get(index..index + 4)
+            .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize)
+    };
+
+    // Reads the size of a NUL-terminated string at the specified index, terminator included.
+    let read_cstring_size = |index: usize| {
+        data.get(index..data.len() - 1)
+            .and_then(|bytes| bytes.iter().position(|&byte| byte == 0))
+            .map(|position| position + 1)
+    };
+
+    // Reads the size of a length-prefixed string at the specified index, prefix included.
+    let read_string_size = |index: usize| {
+        read_size(index)
+            .filter(|&size| size >= 1 && data.get(index + 4 + size - 1) == Some(&0))
+            .map(|size| size + 4)
+    };
+
+    // Checks the nesting depth, the declared size and the terminator.
+    if depth > DEPTH_LIMIT
+        || data.len() < 5
+        || read_size(0) != Some(data.len())
+        || data[data.len() - 1] != 0
+    {
+        return false;
+    }
+
+    // Browses the elements.
+    let end = data.len() - 1;
+    let mut index = 4;
+    while index < end {
+        // Reads the element type.
+        let element_type = data[index];
+        index += 1;
+
+        // Skips the element name.
+        match read_cstring_size(index) {
+            Some(size) => index += size,
+            None => return false,
+        }
+
+        // Calculates the element value size.
+        let size = match element_type {
+            0x06 | 0x0A | 0x7F | 0xFF => Some(0),
+            0x08 => matches!(data.get(index), Some(0 | 1)).then(|| 1),
+            0x10 => Some(4),
+            0x01 | 0x09 | 0x11 | 0x12 => Some(8),
+            0x07 => Some(12),
+            0x13 => Some(16),
+            0x02 | 0x0D | 0x0E => read_string_size(index),
+            0x03 | 0x04 => read_size(index).filter(|&size| {
+                size <= end - index && is_bson_document(&data[index..index + size], depth + 1)
+            }),
+            0x05 => read_size(index).map(|size| size + 5),
+            0x0B => read_cstring_size(index)
+                .and_then(|size| read_cstring_size(index + size).map(|next| size + next)),
+            0x0C => read_string_size(index).map(|size| size + 12),
+            0x0F => read_size(index).filter(|&size| size >= 14),
+            _ => None,
+        };
+
+        // Skips the element value.
+        match size {
+            Some(size) if size <= end - index => index += size,
+            _ => return false,
+        }
+    }
+    true
+}
+
+/// Checks whether a data array starts with the framing of a Cap'n Proto message spanning the
+/// specified length.
+#[cfg(feature = "reader-capnp")]
+fn is_capnp_message(data: &[u8], length: u64) -> bool {
+    // Maximum number of segments of a message.
+    const SEGMENT_LIMIT: usize = 512;
+
+    // Size of a word.
+    const WORD_SIZE: u64 = 8;
+
+    // Reads a little-endian 32-bit value at the specified index.
+    let read_u32 = |index: usize| {
+        data.get(index..index + 4)
+            .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    };
+
+    // Reads the number of segments.
+    let segment_count = match read_u32(0) {
+        Some(count) if (count as usize) < SEGMENT_LIMIT => count as usize + 1,
+        _ => return false,
+    };
+
+    // Checks that the header padding is zeroed.
+    let header_size = (4 + 4 * segment_count + 7) / 8 * 8;
+    if data.len() < header_size + WORD_SIZE as usize
+        || data[4 + 4 * segment_count..header_size]
+            .iter()
+            .any(|&byte| byte != 0)
+    {
+        return false;
+    }
+
+    // Calculates the message size from the segment sizes.
+    let mut size = header_size as u64;
+    for index in 0..segment_count {
+        match read_u32(4 + 4 * index) {
+            Some(words) => size += words as u64 * WORD_SIZE,
+            None => return false,
+        }
+    }
+
+    // Reads the size of the first segment and the root pointer.
+    let first_segment_words = read_u32(4).unwrap_or_default() as i64;
+    let (pointer_low, pointer_high) = match (read_u32(header_size), read_u32(header_size + 4)) {
+        (Some(pointer_low), Some(pointer_high)) => (pointer_low, pointer_high),
+        _ => return false,
+    };
+
+    // Checks that the root pointer is a far pointer or a struct pointer fitting the first segment.
+    let root_fits = match pointer_low & 3 {
+        0 => {
+            let offset = (pointer_low as i32 >> 2) as i64;
+            let words = (pointer_high & 0xFFFF) as i64 + (pointer_high >> 16) as i64;
+            offset >= 0 && 1 + offset + words <= first_segment_words
+        }
+        2 => true,
+        _ => false,
+    };
+    first_segment_words > 0 && (pointer_low, pointer_high) != (0, 0) && root_fits && size == length
+}
+
+/// Checks whether a data array starts with the first word of a Cap'n Proto message compressed with
+/// the packing scheme, holding a valid number of segments and a non-empty first segment.
+#[cfg(feature = "reader-capnp")]
+fn is_capnp_packed_message_start(data: &[u8]) -> bool {
+    // Maximum number of segments of a message.
+    const SEGMENT_LIMIT: u32 = 512;
+
+    // Expands the first word, whose tag byte bits indicate the non-zero bytes.
+    let (&tag, mut rest) = match data.split_first() {
+        Some(split) => split,
+        None => return false,
+    };
+    let mut word = [0; 8];
+    for (bit, byte) in word.iter_mut().enumerate() {
+        if tag & (1 << bit) != 0 {
+            match rest.split_first() {
+                Some((&value, next)) => {
+                    *byte = value;
+                    rest = next;
+                }
+                None => return false,
+            }
+        }
+    }
+
+    // Checks the number of segments and the size of the first segment.
+    u32::from_le_bytes([word[0], word[1], word[2], word[3]]) < SEGMENT_LIMIT
+        && u32::from_le_bytes([word[4], word[5], word[6], word[7]]) > 0
+}
+
+/// Unpacks a Cap'n Proto message compressed with the packing scheme, up to the specified size.
+#[cfg(feature = "reader-capnp")]
+fn unpack_capnp_message(data: &[u8], limit: usize) -> Option<Vec<u8>> {
+    let mut unpacked = Vec::new();
+    let mut index = 0;
+    while index < data.len() {
+        // Reads the tag byte, whose bits indicate the non-zero bytes of the word.
+        let tag = data[index];
+        index += 1;
+
+        // Expands the word.
+        for bit in 0..8 {
+            if tag & (1 << bit) != 0 {
+                unpacked.push(*data.get(index)?);
+                index += 1;
+            } else {
+                unpacked.push(0);
+            }
+        }
+
+        // Expands the run of zeroed or uncompressed words following the word.
+        if tag == 0x00 || tag == 0xFF {
+            let words = *data.get(index)? as usize;
+            index += 1;
+            if tag == 0x00 {
+                unpacked.resize(unpacked.len() + words * 8, 0);
+            } else {
+                unpacked.extend_from_slice(data.get(index..index + words * 8)?);
+                index += words * 8;
+            }
+        }
+
+        // Checks the size of the unpacked message.
+        if unpacked.len() > limit {
+            return None;
+        }
+    }
+    Some(unpacked)
+}
+
+/// Checks whether a data array is a FlatBuffers buffer having a file identifier.
+#[cfg(feature = "reader-flatbuffers")]
+fn is_flatbuffers_buffer(data: &[u8]) -> bool {
+    // Reads a little-endian 16-bit value at the specified index.
+    let read_u16 = |index: usize| {
+        data.get(index..index + 2)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]) as usize)
+    };
+
+    // Reads a little-endian 32-bit value at the specified index.
+    let read_u32 = |index: usize| {
+        data.get(index..index + 4)
+            .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    };
+
+    // Checks the root table offset and the file identifier.
+    let root = match read_u32(0) {
+        Some(root) if root >= 8 && root % 4 == 0 => root as usize,
+        _ => return false,
+    };
+    match data.get(4..8) {
+        Some(identifier) if identifier.iter().all(u8::is_ascii_alphanumeric) => {}
+        _ => return false,
+    }
+
+    // Locates the vtable of the root table.
+    let vtable = match read_u32(root) {
+        Some(offset) => root as i64 - offset as i32 as i64,
+        None => return false,
+    };
+    if vtable < 8 || vtable % 2 != 0 {
+        return false;
+    }
+    let vtable = vtable as usize;
+
+    // Checks the vtable and the root table sizes.
+    let (vtable_size, table_size) = match (read_u16(vtable), read_u16(vtable + 2)) {
+        (Some(vtable_size), Some(table_size))
+            if vtable_size >= 4
+                && vtable_size % 2 == 0
+                && vtable + vtable_size <= data.len()
+                && table_size >= 4
+                && root + table_size <= data.len() =>
+        {
+            (vtable_size, table_size)
+        }
+        _ => return false,
+    };
+
+    // Checks that the fields lie within the root table.
+    (vtable + 4..vtable + vtable_size)
+        .step_by(2)
+        .filter_map(read_u16)
+        .all(|offset| offset == 0 || (4..table_size).contains(&offset))
+}
+
+/// Checks whether a data array is a plausible internal header of a Super Nintendo ROM.
+#[cfg(feature = "reader-sfc")]
+fn is_sfc_header(data: &[u8; 32]) -> bool {
+    // Checks that the title only contains ASCII or JIS X 0201 characters.
+    if !data[..21]
+        .iter()
+        .all(|&byte| (0x20..0x7F).contains(&byte) || (0xA1..0xE0).contains(&byte))
+    {
+        return false;
+    }
+
+    // Checks the map mode, which combines the speed and the memory mapping.
+    if !matches!(data[21] & 0xEF, 0x20 | 0x21 | 0x22 | 0x23 | 0x25 | 0x2A) {
+        return false;
+    }
+
+    // Checks the ROM size, expressed as a power of two in kilobytes (32 KB to 8 MB).
+    if !(0x05..=0x0D).contains(&data[23]) {
+        return false;
+    }
+
+    // Checks that the checksum complement matches the checksum.
+    let complement = u16::from_le_bytes([data[28], data[29]]);
+    let checksum = u16::from_le_bytes([data[30], data[31]]);
+    complement ^ checksum == 0xFFFF
+}
+
+/// Checks whether a data array starts with a Thrift message envelope encoded with the binary or
+/// compact protocol, followed by a plausible field header.
+#[cfg(feature = "reader-thrift")]
+fn is_thrift_message(data: &[u8]) -> bool {
+    // Maximum length of a message name.
+    const NAME_LIMIT: usize = 256;
+
+    // Checks whether a message name is a plausible identifier.
+    let is_name = |name: &[u8]| {
+        matches!(name.first(), Some(byte) if byte.is_ascii_alphabetic() || *byte == b'_')
+            && name.len() <= NAME_LIMIT
+            && name
+                .iter()
+                .all(|&byte| byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'.')
+    };
+
+    // Reads a varint at the specified index, along with its size.
+    let read_varint = |index: usize| {
+        let mut value = 0u64;
+        for (position, &byte) in data.get(index..)?.iter().take(10).enumerate() {
+            value |= ((byte & 0x7F) as u64) << (7 * position);
+            if byte & 0x80 == 0 {
+                return Some((value, position + 1));
+            }
+        }
+        None
+    };
+
+    match data {
+        // Binary protocol: version, message type, name length, name, sequence id.
+        [0x80, 0x01, 0x00, 1..=4, rest @ ..] => {
+            let name_length = match rest.get(..4) {
+                Some(bytes) => {
+                    u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize
+                }
+                None => return false,
+            };
+            if name_length > NAME_LIMIT || !rest.get(4..4 + name_length).map_or(false, is_name) {
+                return false;
+            }
+
+            // Checks the type of the first field, which is followed by its identifier.
+            let index = 4 + name_length + 4;
+            match rest.get(index) {
+                Some(0) => true,
+                Some(2..=4 | 6 | 8 | 10..=16) => rest.len() >= index + 3,
+                _ => false,
+            }
+        }
+
+        // Compact protocol: protocol id, message type and version, sequence id, name length, name.
+        [0x82, header, ..] if header & 0x1F == 1 && (1..=4).contains(&(header >> 5)) => {
+            let (_, sequence_id_size) = match read_varint(2) {
+                Some(varint) => varint,
+                None => return false,
+            };
+            let index = 2 + sequence_id_size;
+            let (name_length, name_length_size) = match read_varint(index) {
+                Some((length, size)) if length as usize <= NAME_LIMIT => (length as usize, size),
+                _ => return false,
+            };
+            let index = index + name_length_size;
+            if !data.get(index..index + name_length).map_or(false, is_name) {
+                return false;
+            }
+
+            // Checks the type of the first field.
+            matches!(data.get(index + name_length), Some(byte) if byte & 0x0F <= 12)
+        }
+        _ => false,
+    }
+}
+
+/// Reads the coder IDs of the folders of a 7z streams info, which starts with the pack info.
+#[cfg(feature = "reader-7z")]
+fn read_7z_streams_info_coders(mut data: &[u8]) -> Option<Vec<Vec<u8>>> {
+    // Maximum number of folders or coders that can be processed.
+    const LIMIT: u64 = 64;
+
+    // Property IDs.
+    const END: u8 = 0x00;
+    const PACK_INFO: u8 = 0x06;
+    const UNPACK_INFO: u8 = 0x07;
+    const SIZE: u8 = 0x09;
+    const CRC: u8 = 0x0A;
+    const FOLDER: u8 = 0x0B;
+
+    // Skips the pack info.
+    if data.first() == Some(&PACK_INFO) {
+        data = &data[1..];
+        read_7z_number(&mut data)?;
+        let pack_streams = read_7z_number(&mut data)?;
+        if pack_streams > LIMIT {
+            return None;
+        }
+        loop {
+            let (&id, rest) = data.split_first()?;
+            data = rest;
+            match id {
+                END => break,
+                SIZE => {
+                    for _ in 0..pack_streams {
+                        read_7z_number(&mut data)?;
+                    }
+                }
+                CRC => {
+                    let (&all_defined, rest) = data.split_first()?;
+                    data = rest;
+                    let defined = if all_defined == 0 {
+                        let size = (pack_streams as usize + 7) / 8;
+                        let bits = data.get(..size)?;
+                        data = &data[size..];
+                        bits.iter().map(|byte| byte.count_ones() as usize).sum()
+                    } else {
+                        pack_streams as usize
+                    };
+                    data = data.get(defined.checked_mul(4)?..)?;
+                }
+                _ => return None,
+            }
+        }
+    }
+
+    // Reads the coders of the folders from the unpack info.
+    if data.get(..2)? != [UNPACK_INFO, FOLDER] {
+        return None;
+    }
+    data = &data[2..];
+    let folders = read_7z_number(&mut data)?;
+    let (&external, rest) = data.split_first()?;
+    data = rest;
+    if folders > LIMIT || external != 0 {
+        return None;
+    }
+    let mut coder_ids = Vec::new();
+    for _ in 0..folders {
+        let coders = read_7z_number(&mut data)?;
+        if coders > LIMIT {
+            return None;
+        }
+        let (mut in_streams, mut out_streams) = (0, 0);
+        for _ in 0..coders {
+            let (&flags, rest) = data.split_first()?;
+            let id_size = (flags & 0x0F) as usize;
+            coder_ids.push(rest.get(..id_size)?.to_vec());
+            data = &rest[id_size..];
+            if flags & 0x10 != 0 {
+                in_streams = read_7z_number(&mut data)?.checked_add(in_streams)?;
+                out_streams = read_7z_number(&mut data)?.checked_add(out_streams)?;
+            } else {
+                in_streams = in_streams.checked_add(1)?;
+                out_streams = out_streams.checked_add(1)?;
+            }
+            if flags & 0x20 != 0 {
+                let size = read_7z_number(&mut data)?;
+                data = data.get(usize::try_from(size).ok()?..)?;
+            }
+        }
+        if in_streams > LIMIT || out_streams == 0 || in_streams + 1 < out_streams {
+            return None;
+        }
+        for _ in 0..2 * (out_streams - 1) {
+            read_7z_number(&mut data)?;
+        }
+        let packed_streams = in_streams + 1 - out_streams;
+        if packed_streams > 1 {
+            for _ in 0..packed_streams {
+                read_7z_number(&mut data)?;
+            }
+        }
+    }
+    Some(coder_ids)
+}
+
+/// Reads a 7z variable-length number, whose first byte tells the number of bytes that follow by
+/// its leading ones.
+#[cfg(feature = "reader-7z")]
+fn read_7z_number(data: &mut &[u8]) -> Option<u64> {
+    let (&first, rest) = data.split_first()?;
+    let extra_bytes = first.leading_ones() as usize;
+    let mut value = 0;
+    for (index, &byte) in rest.get(..extra_bytes)?.iter().enumerate() {
+        value |= (byte as u64) << (8 * index);
+    }
+    if extra_bytes < 8 {
+        value |= ((first & (0x7F >> extra_bytes)) as u64) << (8 * extra_bytes);
+    }
+    *data = &rest[extra_bytes..];
+    Some(value)
+}
+
+/// Calculates the size of the BER-encoded ASN.1 element a data array starts with, checking the
+/// structure of its constructed content.
+#[cfg(feature = "reader-asn1")]
+fn asn1_element_size(data: &[u8], depth: usize) -> Option<usize> {
+    // Maximum nesting depth of the elements.
+    const DEPTH_LIMIT: usize = 32;
+
+    // Checks the nesting depth.
+    if depth > DEPTH_LIMIT {
+        return None;
+    }
+
+    // Reads the tag, which cannot be an end-of-contents marker.
+    let tag = *data.first().filter(|&&tag| tag != 0)?;
+    let constructed = tag & 0x20 != 0;
+    let mut index = 1;
+    if tag & 0x1F == 0x1F {
+        while *data.get(index)? & 0x80 != 0 {
+            index += 1;
+            if index > 5 {
+                return None;
+            }
+        }
+        index += 1;
+    }
+
+    // Reads the length.
+    let length = match *data.get(index)? {
+        0x80 if constructed => {
+            // Browses the nested elements up to the end-of-contents marker.
+            index += 1;
+            while data.get(index..index + 2)? != [0, 0] {
+                index += asn1_element_size(&data[index..], depth + 1)?;
+            }
+            return Some(index + 2);
+        }
+        length @ 0x00..=0x7F => {
+            index += 1;
+            length as usize
+        }
+        size @ 0x81..=0x84 => {
+            let size = (size & 0x7F) as usize;
+            let bytes = data.get(index + 1..index + 1 + size)?;
+            index += 1 + size;
+            bytes
+                .iter()
+                .fold(0, |length, &byte| length << 8 | byte as usize)
+        }
+        _ => return None,
+    };
+
+    // Checks the content, which consists of nested elements if the element is constructed.
+    let content = data.get(index..index.checked_add(length)?)?;
+    if constructed {
+        let mut content_index = 0;
+        while content_index < content.len() {
+            content_index += asn1_element_size(&content[content_index..], depth + 1)?;
+        }
+    }
+    Some(index + length)
+}
+
+/// Directory entry of a CFB file.
+#[cfg(feature = "reader-cfb")]
+struct CfbDirectoryEntry {
+    name: String,
+    starting_sector_location: u32,
+    stream_size: u64,
+}
+
+/// Reads the directory entries of a CFB file, following the directory sector chain.
+#[cfg(feature = "reader-cfb")]
+fn cfb_directory_entries<R: Read + Seek>(
+    reader: &mut R,
+    sector_size: u64,
+    first_directory_sector_location: u32,
+) -> Result<Vec<CfbDirectoryEntry>> {
+    // Maximum number of directory sectors that can be processed by the reader.
+    const MAX_DIRECTORY_SECTORS: usize = 64;
+
+    let mut entries = Vec::new();
+    let mut sector = Some(first_directory_sector_location);
+    let mut buffer = vec![0; sector_size as usize];
+    let mut count = 0;
+    while let Some(location) = sector {
+        // Limits the number of directory sectors.
+        count += 1;
+        if count > MAX_DIRECTORY_SECTORS {
+            break;
+        }
+
+        // Reads the directory sector.
+        reader.seek(SeekFrom::Start(sector_size * (1 + location as u64)))?;
+        if reader.read_exact(&mut buffer).is_err() {
+            break;
+        }
+
+        // Decodes the directory entries.
+        for entry in buffer.chunks_exact(128) {
+            let name_length = (u16::from_le_bytes([entry[64], entry[65]]) as usize).min(64);
+            if entry[66] == 0 || name_length < 2 {
+                continue;
+            }
+            let name: Vec<u16> = entry[..name_length - 2]
+                .chunks_exact(2)
+                .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+                .collect();
+            entries.push(CfbDirectoryEntry {
+                name: String::from_utf16_lossy(&name),
+                starting_sector_location: u32::from_le_bytes(entry[116..120].try_into().unwrap()),
+                stream_size: u64::from_le_bytes(entry[120..128].try_into().unwrap()),
+            });
+        }
+
+        sector = cfb_next_sector_location(reader, sector_size, location)?;
+    }
+    Ok(entries)
+}
+
+/// Gets the location of the sector following the given one in its chain, looking it up in the FAT
+/// sectors listed in the header.
+#[cfg(feature = "reader-cfb")]
+fn cfb_next_sector_location<R: Read + Seek>(
+    reader: &mut R,
+    sector_size: u64,
+    sector_location: u32,
+) -> Result<Option<u32>> {
+    // Number of FAT sector locations stored in the header.
+    const HEADER_DIFAT_ENTRIES: u32 = 109;
+
+    // Special value marking the end of a sector chain.
+    const END_OF_CHAIN: u32 = 0xFFFFFFFE;
+
+    // Reads the location of the FAT sector holding the entry.
+    let entries_per_sector = (sector_size / 4) as u32;
+    let fat_index = sector_location / entries_per_sector;
+    if fat_index >= HEADER_DIFAT_ENTRIES {
+        return Ok(None);
+    }
+    let mut fat_sector_location = [0; 4];
+    reader.seek(SeekFrom::Start(76 + 4 * fat_index as u64))?;
+    reader.read_exact(&mut fat_sector_location)?;
+    let fat_sector_location = u32::from_le_bytes(fat_sector_location);
+
+    // Reads the entry.
+    let mut next_sector_location = [0; 4];
+    reader.seek(SeekFrom::Start(
+        sector_size * (1 + fat_sector_location as u64)
+            + 4 * (sector_location % entries_per_sector) as u64,
+    ))?;
+    if reader.read_exact(&mut next_sector_location).is_err() {
+        return Ok(None);
+    }
+    let next_sector_location = u32::from_le_bytes(next_sector_location);
+    Ok((next_sector_location < END_OF_CHAIN).then(|| next_sector_location))
+}
+
+/// Reads the first bytes of a CFB stream, looking them up in the mini stream held by the root entry
+/// when the stream is smaller than the cutoff size.
+#[cfg(feature = "reader-cfb")]
+fn cfb_read_stream_start<R: Read + Seek>(
+    reader: &mut R,
+    sector_size: u64,
+    root_entry: &CfbDirectoryEntry,
+    entry: &CfbDirectoryEntry,
+    buffer: &mut [u8],
+) -> Result<()> {
+    // Size of a mini stream sector.
+    const MINI_SECTOR_SIZE: u64 = 64;
+
+    // Maximum number of mini stream sectors that can be walked by the reader.
+    const MAX_MINI_STREAM_SECTORS: u64 = 64;
+
+    // Reads the mini stream cutoff size.
+    let mut mini_stream_cutoff_size = [0; 4];
+    reader.seek(SeekFrom::Start(56))?;
+    reader.read_exact(&mut mini_stream_cutoff_size)?;
+    let mini_stream_cutoff_size = u32::from_le_bytes(mini_stream_cutoff_size) as u64;
+
+    // Computes the offset of the stream.
+    let offset = if entry.stream_size < mini_stream_cutoff_size {
+        // Walks the mini stream chain up to the sector holding the stream.
+        let offset = entry.starting_sector_location as u64 * MINI_SECTOR_SIZE;
+        if offset >= root_entry.stream_size || offset / sector_size >= MAX_MINI_STREAM_SECTORS {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "invalid mini stream offset",
+            ));
+        }
+        let mut sector_location = root_entry.starting_sector_location;
+        for _ in 0..offset / sector_size {
+            sector_location = cfb_next_sector_location(reader, sector_size, sector_location)?
+                .ok_or_else(|| Error::new(ErrorKind::InvalidData, "truncated mini stream"))?;
+        }
+        sector_size * (1 + sector_location as u64) + offset % sector_size
+    } else {
+        sector_size * (1 + entry.starting_sector_location as u64)
+    };
+
+    // Reads the first bytes of the stream.
+    reader.seek(SeekFrom::Start(offset))?;
+    reader.read_exact(buffer)
+}
+
+/// Kind of a JSON value.
+#[cfg(feature = "reader-json")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum JsonKind {
+    Array,
+    Object,
+    Other,
+}
+
+/// Checks whether a data array is a compact JSON Web Token whose header holds an algorithm.
+#[cfg(feature = "reader-json")]
+fn is_json_web_token(data: &[u8]) -> bool {
+    // Splits the token into its header, payload and signature.
+    let token = match data.iter().rposition(|byte| !byte.is_ascii_whitespace()) {
+        Some(index) => &data[..=index],
+        None => return false,
+    };
+    let parts: Vec<&[u8]> = token.split(|&byte| byte == b'.').collect();
+    if parts.len() != 3
+        || parts[..2].iter().any(|part| part.is_empty())
+        || !token
+            .iter()
+            .all(|&byte| byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.'))
+    {
+        return false;
+    }
+
+    // Decodes the header and checks its members.
+    decode_base64url(parts[0])
+        .and_then(|header| scan_json_object(&header, false))
+        .map_or(false, |members| {
+            members.iter().any(|(member, _)| member == b"alg")
+        })
+}
+
+/// Decodes base64url-encoded data without padding.
+#[cfg(feature = "reader-json")]
+fn decode_base64url(data: &[u8]) -> Option<Vec<u8>> {
+    let mut decoded = Vec::with_capacity(data.len() * 3 / 4);
+    let mut accumulator = 0u32;
+    let mut bits = 0;
+    for &byte in data {
+        let value = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'-' => 62,
+            b'_' => 63,
+            _ => return None,
+        };
+        accumulator = accumulator << 6 | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            decoded.push((accumulator >> bits) as u8);
+        }
+    }
+    Some(decoded)
+}
+
+/// Scans a data array starting with a JSON object, returning the paths of the members of the
+/// top-level object and of their nested objects, along with the kinds of their values.
+///
+/// The object is allowed to be incomplete if the data is truncated.
+#[cfg(feature = "reader-json")]
+fn scan_json_object(data: &[u8], truncated: bool) -> Option<Vec<(Vec<u8>, JsonKind)>> {
+    // Checks that the data starts with an object.
+    let index = skip_json_whitespace(data, 0);
+    if data.get(index) != Some(&b'{') {
+        return None;
+    }
+
+    // Scans the object and checks that it is only followed by whitespace.
+    let mut members = Vec::new();
+    let index = scan_json_value(data, index, truncated, &mut Vec::new(), &mut members, 0)?;
+    (skip_json_whitespace(data, index) == data.len()).then(|| members)
+}
+
+/// Scans a JSON value at the specified index, recording the paths of the object members up to the
+/// second level, and returns the index following the value.
+///
+/// Reaching the end of truncated data stops the scan and returns its length.
+#[cfg(feature = "reader-json")]
+fn scan_json_value(
+    data: &[u8],
+    index: usize,
+    truncated: bool,
+    path: &mut Vec<u8>,
+    members: &mut Vec<(Vec<u8>, JsonKind)>,
+    depth: usize,
+) -> Option<usize> {
+    // Maximum nesting depth of the values.
+    const DEPTH_LIMIT: usize = 64;
+
+    // Maximum number of levels of the recorded members.
+    const LEVEL_LIMIT: usize = 2;
+
+    // Checks the nesting depth.
+    if depth > DEPTH_LIMIT {
+        return None;
+    }
+
+    // Reads the first byte of the value.
+    let mut index = skip_json_whitespace(data, index);
+    let byte = match data.get(index) {
+        Some(&byte) => byte,
+        None => return truncated.then(|| data.len()),
+    };
+
+    match byte {
+        open @ (b'{' | b'[') => {
+            let close = if open == b'{' { b'}' } else { b']' };
+            index = skip_json_whitespace(data, index + 1);
+            if data.get(index) == Some(&close) {
+                return Some(index + 1);
+            }
+            loop {
+                // Scans the member name, if any.
+                let length = path.len();
+                if open == b'{' {
+                    let (name, next) = scan_json_string(data, index, truncated)?;
+                    index = skip_json_whitespace(data, next);
+                    match data.get(index) {
+                        None => return truncated.then(|| data.len()),
+                        Some(b':') => index += 1,
+                        Some(_) => return None,
+                    }
+
+                    // Records the member path and the kind of its value.
+                    if !path.is_empty() {
+                        path.push(b'/');
+                    }
+                    path.extend_from_slice(name);
+                    if path.iter().filter(|&&byte| byte == b'/').count() < LEVEL_LIMIT {
+                        let kind = match data.get(skip_json_whitespace(data, index)) {
+                            Some(b'[') => JsonKind::Array,
+                            Some(b'{') => JsonKind::Object,
+                            _ => JsonKind::Other,
+                        };
+                        members.push((path.clone(), kind));
+                    }
+                }
+
+                // Scans the value.
+                index = scan_json_value(data, index, truncated, path, members, depth + 1)?;
+                path.truncate(length);
+                index = skip_json_whitespace(data, index);
+                match data.get(index) {
+                    None => return truncated.then(|| data.len()),
+                    Some(b',') => index = skip_json_whitespace(data, index + 1),
+                    Some(&byte) if byte == close => return Some(index + 1),
+                    Some(_) => return None,
+                }
+            }
+        }
+        b'"' => scan_json_string(data, index, truncated).map(|(_, next)| next),
+        b'-' | b'0'..=b'9' => Some(
+            index
+                + data[index..]
+                    .iter()
+                    .position(|byte| {
+                        !matches!(byte, b'+' | b'-' | b'.' | b'0'..=b'9' | b'E' | b'e')
+                    })
+                    .unwrap_or(data.len() - index),
+        ),
+        _ => [&b"false"[..], b"null", b"true"]
+            .iter()
+            .find_map(|literal| {
+                let rest = &data[index..];
+                if rest.starts_with(literal) {
+                    Some(index + literal.len())
+                } else if literal.starts_with(rest) {
+                    Some(data.len())
+                } else {
+                    None
+                }
+            }),
+    }
+}
+
+/// Scans a JSON string at the specified index, returning its raw content and the index following
+/// it.
+///
+/// Reaching the end of truncated data stops the scan and returns its length.
+#[cfg(feature = "reader-json")]
+fn scan_json_string(data: &[u8], index: usize, truncated: bool) -> Option<(&[u8], usize)> {
+    if index >= data.len() {
+        return truncated.then(|| (&[][..], data.len()));
+    }
+    if data[index] != b'"' {
+        return None;
+    }
+    let mut end = index + 1;
+    while end < data.len() {
+        match data[end] {
+            b'"' => return Some((&data[index + 1..end], end + 1)),
+            b'\\' => end += 2,
+            0x00..=0x1F => return None,
+            _ => end += 1,
+        }
+    }
+    truncated.then(|| (&data[index + 1..], data.len()))
+}
+
+/// Returns the index of the first non-whitespace byte of a data array from the specified index.
+#[cfg(feature = "reader-json")]
+fn skip_json_whitespace(data: &[u8], index: usize) -> usize {
+    index
+        + data
+            .get(index..)
+            .unwrap_or_default()
+            .iter()
+            .position(|byte| !matches!(byte, b'\t' | b'\n' | b'\r' | b' '))
+            .unwrap_or(data.len().saturating_sub(index))
+}
+
+/// Checks whether the events of a MIDI track contain lyrics, either as lyric meta-events or as the
+/// text meta-events marking Soft Karaoke files.
+#[cfg(feature = "reader-midi")]
+fn midi_track_has_lyrics(mut data: &[u8]) -> Option<bool> {
+    let mut running_status = 0;
+    while !data.is_empty() {
+        // Skips the delta time, then reads the status, which may be omitted after a channel event.
+        read_midi_number(&mut data)?;
+        let status = match *data.first()? {
+            status if status >= 0x80 => {
+                data = &data[1..];
+                status
+            }
+            _ if running_status != 0 => running_status,
+            _ => return None,
+        };
+
+        // Checks the meta-events and skips the other events.
+        match status {
+            0xFF => {
+                let (&kind, rest) = data.split_first()?;
+                data = rest;
+                let length = read_midi_number(&mut data)? as usize;
+                let text = data.get(..length)?;
+                if kind == 0x05 || kind == 0x01 && text.starts_with(b"@K") {
+                    return Some(true);
+                }
+                data = &data[length..];
+            }
+            0xF0 | 0xF7 => {
+                let length = read_midi_number(&mut data)? as usize;
+                data = data.get(length..)?;
+            }
+            0x80..=0xEF => {
+                running_status = status;
+                let length = if (0xC0..=0xDF).contains(&status) {
+                    1
+                } else {
+                    2
+                };
+                data = data.get(length..)?;
+            }
+            _ => return None,
+        }
+    }
+    Some(false)
+}
+
+/// Reads a MIDI variable-length number, made of up to 4 bytes of 7 bits each.
+#[cfg(feature = "reader-midi")]
+fn read_midi_number(data: &mut &[u8]) -> Option<u32> {
+    let mut value = 0;
+    for index in 0..4 {
+        let byte = *data.get(index)?;
+        value = (value << 7) | (byte & 0x7F) as u32;
+        if byte < 0x80 {
+            *data = &data[index + 1..];
+            return Some(value);
+        }
+    }
+    None
+}
+
+/// Checks whether a data array is an Adobe font metrics file, starting with the header line giving
+/// the version of the format.
+#[cfg(feature = "reader-txt")]
+fn is_adobe_font_metrics(data: &[u8]) -> bool {
+    let line = data.split(|&byte| byte == b'\n').next().unwrap_or_default();
+    let mut fields = std::str::from_utf8(line)
+        .unwrap_or_default()
+        .split_whitespace();
+    fields.next() == Some("StartFontMetrics")
+        && fields
+            .next()
+            .map_or(false, |version| version.parse::<f64>().is_ok())
+        && fields.next().is_none()
+}
+
+/// Checks whether a data array is Base64 text, optionally wrapped in PEM-like armor, made of
+/// lines of the same length holding at least 64 characters of the Base64 alphabet.
+#[cfg(feature = "reader-txt")]
+fn is_base64(data: &[u8]) -> bool {
+    // Minimum number of encoded characters.
+    const LENGTH_MIN: usize = 64;
+
+    // Collects the non-empty lines, without the armor.
+    let mut lines = data
+        .split(|&byte| byte == b'\n')
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+    if lines
+        .first()
+        .map_or(false, |line| line.starts_with(b"-----BEGIN "))
+    {
+        lines.remove(0);
+        if lines
+            .last()
+            .map_or(false, |line| line.starts_with(b"-----END "))
+        {
+            lines.pop();
+        }
+    }
+
+    // Checks the alphabet, the padding and the length of the lines.
+    let text = lines.concat();
+    let padding = text.iter().rev().take_while(|&&byte| byte == b'=').count();
+    let (body, last) = match lines.split_last() {
+        Some((last, body)) => (body, last),
+        None => return false,
+    };
+    text.len() >= LENGTH_MIN
+        && padding <= 2
+        && text[..text.len() - padding]
+            .iter()
+            .all(|&byte| byte.is_ascii_alphanumeric() || byte == b'+' || byte == b'/')
+        && text
+            .iter()
+            .any(|&byte| !byte.is_ascii_hexdigit() && byte != b'=')
+        && text
+            .iter()
+            .any(|&byte| byte.is_ascii_digit() || byte == b'+' || byte == b'/')
+        && body.iter().all(|line| line.len() == body[0].len())
+        && body.first().map_or(true, |first| last.len() <= first.len())
+}
+
+/// Checks whether a data array is an Esri ASCII grid, starting with the header keywords giving the
+/// dimensions, the location and the cell size of the grid.
+#[cfg(feature = "reader-txt")]
+fn is_esri_ascii_grid(data: &[u8]) -> bool {
+    // Keywords of the header, in order, with their alternatives.
+    const KEYWORDS: [&[&str]; 5] = [
+        &["ncols"],
+        &["nrows"],
+        &["xllcenter", "xllcorner"],
+        &["yllcenter", "yllcorner"],
+        &["cellsize"],
+    ];
+
+    // Checks that each line of the header is made of the expected keyword and a number.
+    let mut lines = data.split(|&byte| byte == b'\n');
+    KEYWORDS.iter().all(|keywords| {
+        let line = match lines.next().and_then(|line| std::str::from_utf8(line).ok()) {
+            Some(line) => line,
+            None => return false,
+        };
+        let mut fields = line.split_whitespace();
+        let keyword = fields.next().unwrap_or_default();
+        keywords
+            .iter()
+            .any(|expected| keyword.eq_ignore_ascii_case(expected))
+            && fields
+                .next()
+                .map_or(false, |value| value.parse::<f64>().is_ok())
+            && fields.next().is_none()
+    })
+}
+
+/// Checks whether a data array is a Graphviz DOT graph, starting with a graph header followed by a
+/// block holding node or edge statements.
+#[cfg(feature = "reader-txt")]
+fn is_graphviz_dot(data: &[u8]) -> bool {
+    // Skips the whitespaces and the comments at the specified index.
+    let skip = |mut index: usize| loop {
+        let rest = &data[index.min(data.len())..];
+        let size = if rest.first().map_or(false, u8::is_ascii_whitespace) {
+            1
+        } else if rest.starts_with(b"//") || rest.starts_with(b"#") {
+            rest.iter()
+                .position(|&byte| byte == b'\n')
+                .unwrap_or(rest.len())
+        } else if rest.starts_with(b"/*") {
+            find(rest, b"*/").map_or(rest.len(), |end| end + 2)
+        } else {
+            return index.min(data.len());
+        };
+        index += size;
+    };
+
+    // Reads the word at the specified index, in lowercase.
+    let word = |index: usize| {
+        let size = data[index..]
+            .iter()
+            .take_while(|&&byte| byte.is_ascii_alphanumeric() || byte == b'_')
+            .count();
+        (data[index..index + size].to_ascii_lowercase(), index + size)
+    };
+
+    // Reads the optional strict keyword and the graph keyword.
+    let (mut keyword, mut index) = word(skip(0));
+    if keyword == b"strict" {
+        (keyword, index) = word(skip(index));
+    }
+    if keyword != b"graph" && keyword != b"digraph" {
+        return false;
+    }
+
+    // Skips the optional graph identifier.
+    index = skip(index);
+    if data.get(index) == Some(&b'"') {
+        match data[index + 1..].iter().position(|&byte| byte == b'"') {
+            Some(end) => index = skip(index + end + 2),
+            None => return false,
+        }
+    } else {
+        let (identifier, next) = word(index);
+        if !identifier.is_empty() {
+            index = skip(next);
+        }
+    }
+
+    // Checks the opening brace, followed by an edge operator or an attribute list.
+    let edge_operator: &[u8] = if keyword == b"digraph" { b"->" } else { b"--" };
+    data.get(index) == Some(&b'{')
+        && (find(&data[index..], edge_operator).is_some() || find(&data[index..], b"[").is_some())
+}
+
+/// Checks whether a data array is a projection in the well-known text format, starting with a
+/// coordinate reference system keyword followed by its bracketed and quoted name.
+#[cfg(feature = "reader-txt")]
+fn is_projection_wkt(data: &[u8]) -> bool {
+    // Keywords a coordinate reference system can start with.
+    const KEYWORDS: &[&[u8]] = &[
+        b"COMPD_CS",
+        b"GEOCCS",
+        b"GEOGCRS",
+        b"GEOGCS",
+        b"PROJCRS",
+        b"PROJCS",
+        b"VERT_CS",
+    ];
+
+    // Skips the leading whitespace.
+    let start = data.iter().position(|byte| !byte.is_ascii_whitespace());
+    let data = &data[start.unwrap_or(data.len())..];
+
+    // Checks the keyword, the opening bracket and the opening quote of the name.
+    KEYWORDS.iter().any(|keyword| {
+        data.strip_prefix(*keyword)
+            .and_then(|rest| rest.strip_prefix(b"[").or_else(|| rest.strip_prefix(b"(")))
+            .and_then(|rest| rest.iter().find(|byte| !byte.is_ascii_whitespace()))
+            == Some(&b'"')
+    })
+}
+
+/// Checks whether a data array is a SQL script, made of several statements, most of them starting
+/// with SQL keywords.
+#[cfg(feature = "reader-txt")]
+fn is_sql_script(data: &[u8]) -> bool {
+    // Minimum number of statements starting with a keyword.
+    const STATEMENT_MIN_COUNT: usize = 2;
+
+    // Keywords a statement can start with.
+    const KEYWORDS: &[&[u8]] = &[
+        b"ALTER TABLE ",
+        b"BEGIN ",
+        b"COMMIT ",
+        b"COPY ",
+        b"CREATE DATABASE ",
+        b"CREATE INDEX ",
+        b"CREATE SCHEMA ",
+        b"CREATE TABLE ",
+        b"CREATE TRIGGER ",
+        b"CREATE UNIQUE INDEX ",
+        b"CREATE VIEW ",
+        b"DELETE FROM ",
+        b"DROP INDEX ",
+        b"DROP TABLE ",
+        b"DROP VIEW ",
+        b"INSERT INTO ",
+        b"LOCK TABLES ",
+        b"PRAGMA ",
+        b"ROLLBACK ",
+        b"SELECT ",
+        b"SET ",
+        b"UNLOCK TABLES ",
+        b"UPDATE ",
+        b"USE ",
+    ];
+
+    // Normalizes the whitespaces and the case of a statement, with a trailing space delimiting the
+    // last word.
+    let normalize = |statement: &[u8]| {
+        let mut normalized = Vec::new();
+        for word in statement
+            .split(|byte| byte.is_ascii_whitespace())
+            .filter(|word| !word.is_empty())
+        {
+            normalized.extend_from_slice(&word.to_ascii_uppercase());
+            normalized.push(b' ');
+        }
+        normalized
+    };
+
+    // Splits the code into normalized statements, skipping the comments, ignoring the semicolons
+    // within quotes or identifiers, and dropping the last unterminated one.
+    let mut statements = Vec::new();
+    let mut statement = Vec::new();
+    let mut quote = None;
+    let mut index = 0;
+    while index < data.len() {
+        let byte = data[index];
+        match quote {
+            Some(b'\'') if byte == b'\\' => {
+                statement.extend(data[index..].iter().take(2));
+                index += 2;
+                continue;
+            }
+            Some(delimiter) => {
+                if byte == delimiter {
+                    quote = None;
+                }
+                statement.push(byte);
+            }
+            None if data[index..].starts_with(b"--") => {
+                index += data[index..]
+                    .iter()
+                    .position(|&byte| byte == b'\n')
+                    .unwrap_or(data.len() - index);
+                continue;
+            }
+            None if data[index..].starts_with(b"/*") => {
+                index += find(&data[index..], b"*/").map_or(data.len() - index, |end| end + 2);
+                continue;
+            }
+            None => match byte {
+                b'\'' | b'"' | b'`' => {
+                    quote = Some(byte);
+                    statement.push(byte);
+                }
+                b';' => {
+                    let normalized = normalize(&statement);
+                    statement.clear();
+
+                    // Skips the rows following a COPY statement, up to their end marker.
+                    if normalized.starts_with(b"COPY ") && normalized.ends_with(b" FROM STDIN ") {
+                        index += find(&data[index..], b"\n\\.")
+                            .map_or(data.len() - index, |end| end + 3);
+                    }
+                    statements.push(normalized);
+                }
+                _ => statement.push(byte),
+            },
+        }
+        index += 1;
+    }
+
+    // Checks that most statements start with a keyword.
+    let statements: Vec<_> = statements
+        .iter()
+        .filter(|statement| !statement.is_empty())
+        .collect();
+    let count = statements
+        .iter()
+        .filter(|statement| {
+            KEYWORDS
+                .iter()
+                .any(|keyword| statement.starts_with(keyword))
+        })
+        .count();
+    count >= STATEMENT_MIN_COUNT && 2 * count > statements.len()
+}
+
+/// Checks whether a data array is a SAMI document, starting with the `SAMI` root element and
+/// holding synchronized captions.
+#[cfg(feature = "reader-txt")]
+fn is_synchronized_accessible_media_interchange(data: &[u8]) -> bool {
+    let data = data.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(data);
+    let start = data
+        .iter()
+        .position(|byte| !byte.is_ascii_whitespace())
+        .unwrap_or(data.len());
+    let root = &data[start..];
+    root.len() > 5
+        && root[..5].eq_ignore_ascii_case(b"<SAMI")
+        && matches!(root[5], b'>' | b' ' | b'\t' | b'\r' | b'\n')
+        && data
+            .windows(5)
+            .any(|window| window.eq_ignore_ascii_case(b"<SYNC"))
+}
+
+/// Checks whether a data array is a context diff, having a file header followed by a hunk.
+#[cfg(feature = "reader-txt")]
+fn is_context_diff(data: &[u8]) -> bool {
+    let lines: Vec<&[u8]> = diff_lines(data).collect();
+    matches!(lines.first(), Some(line) if line.starts_with(b"*** ") || line.starts_with(b"diff "))
+        && lines.windows(4).any(|lines| {
+            lines[0].starts_with(b"*** ")
+                && lines[1].starts_with(b"--- ")
+                && lines[2] == b"***************"
+                && lines[3]
+                    .strip_prefix(b"*** ")
+                    .and_then(strip_diff_range)
+                    .map_or(false, |rest| rest == b" ****")
+        })
+}
+
+/// Checks whether a data array is a unified diff or a Git patch, having a file header followed by
+/// a hunk.
+#[cfg(feature = "reader-txt")]
+fn is_unified_diff(data: &[u8]) -> bool {
+    let lines: Vec<&[u8]> = diff_lines(data).collect();
+    matches!(lines.first(), Some(line) if [&b"--- "[..], b"From ", b"Index: ", b"diff "]
+        .iter()
+        .any(|prefix| line.starts_with(prefix)))
+        && lines.windows(3).any(|lines| {
+            lines[0].starts_with(b"--- ")
+                && lines[1].starts_with(b"+++ ")
+                && lines[2]
+                    .strip_prefix(b"@@ -")
+                    .and_then(strip_diff_range)
+                    .and_then(|rest| rest.strip_prefix(b" +"))
+                    .and_then(strip_diff_range)
+                    .map_or(false, |rest| rest.starts_with(b" @@"))
+        })
+}
+
+/// Splits a data array into lines, without their terminators.
+#[cfg(feature = "reader-txt")]
+fn diff_lines(data: &[u8]) -> impl Iterator<Item = &[u8]> {
+    data.split(|&byte| byte == b'\n')
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+}
+
+/// Strips a line range of a hunk header, such as `12` or `12,7`, from the start of a line.
+#[cfg(feature = "reader-txt")]
+fn strip_diff_range(line: &[u8]) -> Option<&[u8]> {
+    let digits = line.iter().take_while(|byte| byte.is_ascii_digit()).count();
+    if digits == 0 {
+        return None;
+    }
+    let line = &line[digits..];
+    match line.strip_prefix(b",") {
+        Some(rest) => {
+            let digits = rest.iter().take_while(|byte| byte.is_ascii_digit()).count();
+            (digits > 0).then(|| &rest[digits..])
+        }
+        None => Some(line),
+    }
+}
+
+/// Checks whether a data array is a world file, made of exactly six lines holding a number each,
+/// not all integers, the pixel sizes being positive in X and negative in Y (north-up images).
+#[cfg(feature = "reader-txt")]
+fn is_world_file(data: &[u8]) -> bool {
+    // Gets the non-empty lines.
+    let lines = match std::str::from_utf8(data) {
+        Ok(text) => text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>(),
+        Err(_) => return false,
+    };
+
+    // Rejects lists of integers, since the parameters are real numbers.
+    if lines.len() != 6 || lines.iter().all(|line| line.parse::<i64>().is_ok()) {
+        return false;
+    }
+
+    // Parses the lines as numbers and checks the pixel sizes.
+    let values = lines
+        .iter()
+        .map(|line| line.parse::<f64>().ok())
+        .collect::<Option<Vec<_>>>();
+    match values.as_deref() {
+        Some([x_size, _, _, y_size, _, _]) => *x_size > 0.0 && *y_size < 0.0,
+        _ => false,
+    }
+}
+
+/// Checks whether a data array is a Zeek log in the tab-separated format, starting with the
+/// separator directive and declaring its fields in the header.
+#[cfg(feature = "reader-txt")]
+fn is_zeek_log(data: &[u8]) -> bool {
+    let mut lines = data.split(|&byte| byte == b'\n');
+    lines
+        .next()
+        .map_or(false, |line| line.starts_with(b"#separator "))
+        && lines
+            .take_while(|line| line.starts_with(b"#"))
+            .any(|line| line.starts_with(b"#fields"))
+}
+
+/// Finds the first start tag of an XML element in a text, returning its content without the
+/// brackets.
+#[cfg(feature = "reader-xml")]
+fn xml_start_tag<'a>(text: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = text;
+    while let Some(index) = rest.find('<') {
+        rest = &rest[index + 1..];
+        if let Some(after) = rest.strip_prefix(name) {
+            if after.starts_with(|c: char| c.is_whitespace() || c == '>' || c == '/') {
+                return after.find('>').map(|end| &rest[..name.len() + end]);
+            }
+        }
+    }
+    None
+}
+
+/// Reads the value of an attribute from the content of an XML start tag.
+#[cfg(feature = "reader-xml")]
+fn xml_attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = tag;
+    while let Some(index) = rest.find(name) {
+        let before = rest[..index].chars().next_back();
+        rest = &rest[index + name.len()..];
+        if before.map_or(false, char::is_whitespace) {
+            if let Some(value) = rest.trim_start().strip_prefix('=') {
+                let value = value.trim_start();
+                let quote = value.chars().next().filter(|&c| c == '"' || c == '\'')?;
+                let value = &value[1..];
+                return value.find(quote).map(|end| &value[..end]);
+            }
+        }
+    }
+    None
+}
+
+/// Gets the sizes and the local file header offset of a ZIP entry, reading the 64-bit values from
+/// the ZIP64 extended information extra field when the 32-bit ones are saturated.
+#[cfg(feature = "reader-zip")]
+fn zip64_extended_information(
+    extra_field: &[u8],
+    compressed_size: u32,
+    uncompressed_size: u32,
+    offset: u32,
+) -> (u64, u64, u64) {
+    // Header ID of the ZIP64 extended information extra field.
+    const ZIP64_HEADER_ID: u16 = 0x0001;
+
+    // Sets the default values, in the order of the extra field.
+    let mut values = [
+        uncompressed_size as u64,
+        compressed_size as u64,
+        offset as u64,
+    ];
+
+    // Browses the extra fields.
+    let mut index = 0;
+    while index + 4 <= extra_field.len() {
+        let header_id = u16::from_le_bytes([extra_field[index], extra_field[index + 1]]);
+        let data_size =
+            u16::from_le_bytes([extra_field[index + 2], extra_field[index + 3]]) as usize;
+        let data = &extra_field[index + 4..extra_field.len().min(index + 4 + data_size)];
+        if header_id == ZIP64_HEADER_ID {
+            // Only the saturated values are present, in this order.
+            let mut chunks = data.chunks_exact(8);
+            for value in values.iter_mut().filter(|value| **value == u32::MAX as u64) {
+                match chunks.next() {
+                    Some(chunk) => *value = u64::from_le_bytes(chunk.try_into().unwrap()),
+                    None => break,
+                }
+            }
+            break;
+        }
+        index += 4 + data_size;
+    }
+    (values[1], values[0], values[2])
+}
+
+/// Reads the stream flags from the header of an XZ stream, checking their CRC32 and their reserved
+/// bits.
+#[cfg(feature = "reader-xz")]
+fn read_xz_stream_flags<R: Read + Seek>(reader: &mut R) -> Result<[u8; 2]> {
+    reader.rewind()?;
+    let mut header = [0; 12];
+    reader.read_exact(&mut header)?;
+    if crc32(&header[6..8]) != u32::from_le_bytes(header[8..12].try_into().unwrap())
+        || header[6] != 0
+        || header[7] & 0xF0 != 0
+    {
+        return Err(Error::new(ErrorKind::InvalidData, "invalid stream flags"));
+    }
+    Ok([header[6], header[7]])
+}
+
+/// Computes the CRC32 checksum of a data array, as used by XZ.
+#[cfg(feature = "reader-xz")]
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
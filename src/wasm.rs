@@ -0,0 +1,32 @@
+//! JavaScript bindings for WebAssembly targets.
+//!
+//! Only the file formats detected by their signature are recognized unless reader features are
+//! enabled, which keeps the size of the WebAssembly module small.
+
+use crate::FileFormat;
+use js_sys::{Object, Reflect};
+use wasm_bindgen::prelude::*;
+
+/// Determines the file format of bytes, returning an object holding its `name`, `shortName`,
+/// `mediaType`, `extension` and `kind`.
+///
+/// The `shortName` property is `undefined` if the file format has no short name.
+#[wasm_bindgen]
+pub fn detect(bytes: &[u8]) -> JsValue {
+    let format = FileFormat::from_bytes(bytes);
+    let object = Object::new();
+    let properties = [
+        ("name", Some(format.name().to_string())),
+        ("shortName", format.short_name().map(str::to_string)),
+        ("mediaType", Some(format.media_type().to_string())),
+        ("extension", Some(format.extension().to_string())),
+        ("kind", Some(format!("{:?}", format.kind()))),
+    ];
+    for (key, value) in properties {
+        if let Some(value) = value {
+            // Setting a property of a plain object cannot fail.
+            let _ = Reflect::set(&object, &JsValue::from_str(key), &JsValue::from(value));
+        }
+    }
+    object.into()
+}
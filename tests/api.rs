@@ -0,0 +1,577 @@
+use file_format::{Confidence, Detector, FileFormat, ParseFileFormatError, UnknownFormat};
+use std::{
+    io::{Cursor, ErrorKind, Read, Result, Seek, SeekFrom},
+    path::PathBuf,
+};
+
+/// A reader counting the bytes read from the inner reader.
+struct CountingReader<R> {
+    inner: R,
+    bytes_read: usize,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let bytes_read = self.inner.read(buf)?;
+        self.bytes_read += bytes_read;
+        Ok(bytes_read)
+    }
+}
+
+impl<R: Seek> Seek for CountingReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        self.inner.seek(pos)
+    }
+}
+
+#[test]
+fn test_try_from_bytes_known() {
+    let fmt = FileFormat::try_from_bytes(b"%PDF-1.7\n");
+    assert_eq!(fmt, Ok(FileFormat::PortableDocumentFormat));
+}
+
+#[test]
+fn test_try_from_bytes_empty() {
+    let fmt = FileFormat::try_from_bytes(&[]);
+    assert_eq!(fmt, Ok(FileFormat::Empty));
+}
+
+#[test]
+fn test_try_from_bytes_unknown() {
+    let fmt = FileFormat::try_from_bytes(&[0xDE, 0xAD, 0xBE, 0xEF, 0x00]);
+    assert_eq!(fmt, Err(UnknownFormat));
+}
+
+#[test]
+fn test_try_from_reader_known() {
+    let fmt = FileFormat::try_from_reader(Cursor::new(b"\xFF\xD8\xFF\xE0")).unwrap();
+    assert_eq!(fmt, FileFormat::JointPhotographicExpertsGroup);
+}
+
+#[test]
+fn test_try_from_reader_unknown() {
+    let error =
+        FileFormat::try_from_reader(Cursor::new([0xDE, 0xAD, 0xBE, 0xEF, 0x00])).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+    assert_eq!(error.to_string(), "unknown file format");
+    assert!(error.get_ref().unwrap().is::<UnknownFormat>());
+}
+
+#[test]
+fn test_detect_overlays_polyglot() {
+    let bytes = std::fs::read("fixtures/image/sample2.gif").unwrap();
+    let fmts = FileFormat::detect_overlays(&bytes);
+    let payload = if cfg!(feature = "reader-zip") {
+        FileFormat::JavaArchive
+    } else {
+        FileFormat::Zip
+    };
+    assert_eq!(fmts, [FileFormat::GraphicsInterchangeFormat, payload]);
+}
+
+#[test]
+fn test_detect_overlays_single() {
+    let bytes = std::fs::read("fixtures/image/sample1.gif").unwrap();
+    let fmts = FileFormat::detect_overlays(&bytes);
+    assert_eq!(fmts, [FileFormat::GraphicsInterchangeFormat]);
+}
+
+#[test]
+fn test_detect_overlays_pe_section() {
+    // Adds a section holding a ZIP local file header, which is not an overlay.
+    let mut bytes = std::fs::read("fixtures/executable/sample4.exe").unwrap();
+    bytes[70] = 1;
+    bytes.extend(b".rdata\0\0\0\0\0\0\0\0\0\0\x40\0\0\0\x80\0\0\0");
+    bytes.resize(128, 0);
+    bytes.extend(b"PK\x03\x04");
+    bytes.resize(192, 0);
+    let fmts = FileFormat::detect_overlays(&bytes);
+    assert_eq!(fmts.len(), 1);
+}
+
+#[test]
+fn test_detect_overlays_zip() {
+    let bytes = std::fs::read("fixtures/archive/sample1.zip").unwrap();
+    let fmts = FileFormat::detect_overlays(&bytes);
+    assert_eq!(fmts, [FileFormat::Zip]);
+}
+
+#[test]
+fn test_required_feature() {
+    assert_eq!(
+        FileFormat::OfficeOpenXmlDocument.required_feature(),
+        Some("reader-zip")
+    );
+    assert_eq!(FileFormat::PortableNetworkGraphics.required_feature(), None);
+}
+
+#[test]
+fn test_from_bytes_with_confidence_exact() {
+    let fmt = FileFormat::from_bytes_with_confidence(b"\x89\x50\x4E\x47\x0D\x0A\x1A\x0A");
+    assert_eq!(
+        fmt,
+        (FileFormat::PortableNetworkGraphics, Confidence::Exact)
+    );
+    let fmt = FileFormat::from_bytes_with_confidence(b"");
+    assert_eq!(fmt, (FileFormat::Empty, Confidence::Exact));
+}
+
+#[test]
+fn test_from_bytes_with_confidence_heuristic() {
+    let fmt = FileFormat::from_bytes_with_confidence(b"\x0B\x77\0\0");
+    assert_eq!(fmt, (FileFormat::AudioCodec3, Confidence::Heuristic));
+}
+
+#[test]
+fn test_from_bytes_with_confidence_fallback() {
+    let fmt = FileFormat::from_bytes_with_confidence(&[0; 1000]);
+    assert_eq!(fmt, (FileFormat::ArbitraryBinaryData, Confidence::Fallback));
+}
+
+#[cfg(feature = "reader-zip")]
+#[test]
+fn test_from_bytes_footer() {
+    // Prepends data past the first bytes read, so that the archive is only found from its end.
+    let mut bytes = vec![0xA5; 65_536];
+    bytes.extend(std::fs::read("fixtures/document/sample1.docx").unwrap());
+    assert_eq!(
+        FileFormat::from_bytes(&bytes),
+        FileFormat::OfficeOpenXmlDocument
+    );
+}
+
+#[cfg(feature = "reader-zip")]
+#[test]
+fn test_from_bytes_zip64_offset_overflow() {
+    // Builds an archive whose mimetype entry has a ZIP64 local file header offset near u64::MAX.
+    let mut bytes = Vec::new();
+    bytes.extend(b"PK\x03\x04\x14\0\0\0\0\0\0\0\0\0\0\0\0\0\x0A\0\0\0\x0A\0\0\0\x08\0\0\0");
+    bytes.extend(b"mimetypetext/plain");
+    let cd_offset = bytes.len() as u32;
+    bytes.extend(b"PK\x01\x02\x14\0\x14\0\0\0\0\0\0\0\0\0\0\0\0\0\x0A\0\0\0\x0A\0\0\0");
+    bytes.extend(b"\x08\0\x0C\0\0\0\0\0\0\0\0\0\0\0\xFF\xFF\xFF\xFFmimetype");
+    bytes.extend(b"\x01\0\x08\0\xF0\xFF\xFF\xFF\xFF\xFF\xFF\xFF");
+    let cd_size = bytes.len() as u32 - cd_offset;
+    bytes.extend(b"PK\x05\x06\0\0\0\0\x01\0\x01\0");
+    bytes.extend(cd_size.to_le_bytes());
+    bytes.extend(cd_offset.to_le_bytes());
+    bytes.extend(b"\0\0");
+    assert_eq!(
+        FileFormat::from_bytes(&bytes),
+        FileFormat::ArbitraryBinaryData
+    );
+}
+
+#[test]
+fn test_from_bytes_parquet() {
+    let bytes = std::fs::read("fixtures/application/sample.parquet").unwrap();
+    assert_eq!(FileFormat::from_bytes(&bytes), FileFormat::ApacheParquet);
+}
+
+#[cfg(feature = "reader-tga")]
+#[test]
+fn test_from_bytes_tga() {
+    let bytes = std::fs::read("fixtures/image/sample.tga").unwrap();
+    assert_eq!(FileFormat::from_bytes(&bytes), FileFormat::TruevisionTga);
+}
+
+#[test]
+fn test_from_bytes_matches_from_file() {
+    let mut directories = vec![PathBuf::from("fixtures")];
+    while let Some(directory) = directories.pop() {
+        for entry in std::fs::read_dir(directory).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                directories.push(path);
+                continue;
+            }
+            let bytes = std::fs::read(&path).unwrap();
+            let fmt = FileFormat::from_file(&path).unwrap();
+            assert_eq!(FileFormat::from_bytes(&bytes), fmt, "{}", path.display());
+        }
+    }
+}
+
+#[test]
+fn test_id() {
+    assert_eq!(FileFormat::Abiword.id(), 1);
+    assert_eq!(FileFormat::PortableNetworkGraphics.id(), 329);
+    assert_eq!(FileFormat::Zip.id(), 468);
+}
+
+#[test]
+fn test_from_id() {
+    assert_eq!(FileFormat::from_id(1), Some(FileFormat::Abiword));
+    assert_eq!(
+        FileFormat::from_id(329),
+        Some(FileFormat::PortableNetworkGraphics)
+    );
+    assert_eq!(FileFormat::from_id(0), None);
+    assert_eq!(FileFormat::from_id(u32::MAX), None);
+}
+
+#[test]
+fn test_from_str_variant_name() {
+    let fmt = "PortableDocumentFormat".parse();
+    assert_eq!(fmt, Ok(FileFormat::PortableDocumentFormat));
+}
+
+#[test]
+fn test_from_str_name() {
+    let fmt = "Portable Document Format".parse();
+    assert_eq!(fmt, Ok(FileFormat::PortableDocumentFormat));
+}
+
+#[test]
+fn test_from_str_short_name() {
+    let fmt = "PDF".parse();
+    assert_eq!(fmt, Ok(FileFormat::PortableDocumentFormat));
+}
+
+#[test]
+fn test_from_str_ambiguous_short_name() {
+    let fmt = "MP4".parse::<FileFormat>();
+    assert_eq!(fmt, Err(ParseFileFormatError));
+}
+
+#[test]
+fn test_from_str_case_insensitive() {
+    assert_eq!("png".parse(), Ok(FileFormat::PortableNetworkGraphics));
+    assert_eq!(
+        "portable document format".parse(),
+        Ok(FileFormat::PortableDocumentFormat)
+    );
+    assert_eq!(
+        "portabledocumentformat".parse(),
+        Ok(FileFormat::PortableDocumentFormat)
+    );
+}
+
+#[test]
+fn test_from_str_unknown() {
+    assert_eq!("unknown".parse::<FileFormat>(), Err(ParseFileFormatError));
+    assert_eq!("".parse::<FileFormat>(), Err(ParseFileFormatError));
+}
+
+#[test]
+fn test_from_str_display_round_trip() {
+    for fmt in FileFormat::all() {
+        assert_eq!(fmt.to_string().parse(), Ok(fmt));
+    }
+}
+
+#[test]
+fn test_ord_sort() {
+    let mut fmts = vec![
+        FileFormat::Zip,
+        FileFormat::PortableDocumentFormat,
+        FileFormat::Abiword,
+        FileFormat::PortableDocumentFormat,
+    ];
+    fmts.sort();
+    assert_eq!(
+        fmts,
+        [
+            FileFormat::Abiword,
+            FileFormat::PortableDocumentFormat,
+            FileFormat::PortableDocumentFormat,
+            FileFormat::Zip,
+        ]
+    );
+}
+
+#[test]
+fn test_ord_btree_map() {
+    let mut map = std::collections::BTreeMap::new();
+    map.insert(FileFormat::Zip, "zip");
+    map.insert(FileFormat::Abiword, "abw");
+    map.insert(FileFormat::Zip, "zip");
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.keys().next(), Some(&FileFormat::Abiword));
+    assert_eq!(map.get(&FileFormat::Zip), Some(&"zip"));
+}
+
+#[test]
+fn test_all() {
+    let formats = FileFormat::all().collect::<Vec<_>>();
+    let unique = formats.iter().collect::<std::collections::HashSet<_>>();
+    assert_eq!(unique.len(), formats.len());
+    assert!(formats.contains(&FileFormat::ArbitraryBinaryData));
+    assert!(formats.contains(&FileFormat::Zstandard));
+}
+
+#[test]
+fn test_from_extension() {
+    let fmt = FileFormat::from_extension("png");
+    assert_eq!(fmt, Some(FileFormat::PortableNetworkGraphics));
+}
+
+#[test]
+fn test_from_extension_collision() {
+    assert_eq!(
+        FileFormat::from_extension("3ds"),
+        Some(FileFormat::ThreeDimensionalStudio)
+    );
+    assert_eq!(
+        FileFormat::from_extension("exe"),
+        Some(FileFormat::MsDosExecutable)
+    );
+    assert_eq!(FileFormat::from_extension("iso"), Some(FileFormat::Iso9660));
+    assert_eq!(
+        FileFormat::from_extension("tar"),
+        Some(FileFormat::TapeArchive)
+    );
+}
+
+#[test]
+fn test_from_extension_all() {
+    assert_eq!(
+        FileFormat::from_extension_all("tar"),
+        [
+            FileFormat::TapeArchive,
+            FileFormat::DockerImage,
+            FileFormat::OciImageLayout
+        ]
+    );
+    assert_eq!(
+        FileFormat::from_extension_all("png"),
+        [FileFormat::PortableNetworkGraphics]
+    );
+    assert!(FileFormat::from_extension_all("unknown").is_empty());
+}
+
+#[test]
+fn test_from_extension_alias() {
+    assert_eq!(
+        FileFormat::from_extension("jpeg"),
+        Some(FileFormat::JointPhotographicExpertsGroup)
+    );
+    assert_eq!(
+        FileFormat::from_extension("TIF"),
+        Some(FileFormat::TagImageFileFormat)
+    );
+}
+
+#[test]
+fn test_from_extension_case_and_dot() {
+    assert_eq!(
+        FileFormat::from_extension(".PDF"),
+        Some(FileFormat::PortableDocumentFormat)
+    );
+    assert_eq!(
+        FileFormat::from_extension("Z"),
+        Some(FileFormat::UnixCompress)
+    );
+    assert_eq!(
+        FileFormat::from_extension(".z"),
+        Some(FileFormat::UnixCompress)
+    );
+}
+
+#[test]
+fn test_from_extension_unknown() {
+    assert_eq!(FileFormat::from_extension("unknown"), None);
+    assert_eq!(FileFormat::from_extension(""), None);
+}
+
+#[test]
+fn test_from_media_type() {
+    assert_eq!(
+        FileFormat::from_media_type("image/png"),
+        Some(FileFormat::PortableNetworkGraphics)
+    );
+}
+
+#[test]
+fn test_from_media_type_all() {
+    assert_eq!(
+        FileFormat::from_media_type_all("text/x-diff"),
+        [FileFormat::UnifiedDiff, FileFormat::ContextDiff]
+    );
+    assert!(FileFormat::from_media_type_all("application/unknown").is_empty());
+}
+
+#[test]
+fn test_from_media_type_collision() {
+    assert_eq!(
+        FileFormat::from_media_type("video/mp4"),
+        Some(FileFormat::Mpeg4Part14Video)
+    );
+    assert_eq!(
+        FileFormat::from_media_type("application/x-dosexec"),
+        Some(FileFormat::MsDosExecutable)
+    );
+}
+
+#[test]
+fn test_from_media_type_parameters() {
+    assert_eq!(
+        FileFormat::from_media_type(" Application/ZIP ; name=sample.zip"),
+        Some(FileFormat::Zip)
+    );
+}
+
+#[test]
+fn test_from_media_type_unknown() {
+    assert_eq!(FileFormat::from_media_type("unknown/unknown"), None);
+    assert_eq!(FileFormat::from_media_type(""), None);
+}
+
+#[test]
+fn test_from_read() {
+    let bytes = std::fs::read("fixtures/image/sample.png").unwrap();
+    let fmt = FileFormat::from_read(bytes.as_slice()).unwrap();
+    assert_eq!(fmt, FileFormat::PortableNetworkGraphics);
+}
+
+#[test]
+fn test_from_read_endless() {
+    let mut reader = CountingReader {
+        inner: std::io::repeat(0),
+        bytes_read: 0,
+    };
+    let fmt = FileFormat::from_read(&mut reader).unwrap();
+    assert_eq!(fmt, FileFormat::ArbitraryBinaryData);
+    assert_eq!(reader.bytes_read, 36870);
+}
+
+#[cfg(feature = "reader-zip")]
+#[test]
+fn test_from_read_zip() {
+    let mut bytes = std::fs::read("fixtures/document/sample1.docx").unwrap();
+    let fmt = FileFormat::from_read(bytes.as_slice()).unwrap();
+    assert_eq!(fmt, FileFormat::OfficeOpenXmlDocument);
+
+    // Appends data past the first bytes read, so that only the local file headers are in reach.
+    bytes.extend(vec![0xA5; 65_536]);
+    let fmt = FileFormat::from_read(bytes.as_slice()).unwrap();
+    assert_eq!(fmt, FileFormat::OfficeOpenXmlDocument);
+}
+
+#[test]
+fn test_from_reader_with_capacity() {
+    let file = std::fs::File::open("fixtures/disk/sample.iso").unwrap();
+    let fmt = FileFormat::from_reader_with_capacity(file, 65_536).unwrap();
+    assert_eq!(fmt, FileFormat::Iso9660);
+    let file = std::fs::File::open("fixtures/disk/sample.iso").unwrap();
+    let fmt = FileFormat::from_reader_with_capacity(file, 512).unwrap();
+    assert_ne!(fmt, FileFormat::Iso9660);
+}
+
+#[cfg(feature = "reader-zip")]
+#[test]
+fn test_from_reader_with_capacity_zip() {
+    let mut bytes = vec![0xA5; 65_536];
+    bytes.extend(std::fs::read("fixtures/document/sample1.docx").unwrap());
+    let fmt = FileFormat::from_reader_with_capacity(Cursor::new(bytes), 512).unwrap();
+    assert_eq!(fmt, FileFormat::OfficeOpenXmlDocument);
+}
+
+#[test]
+fn test_from_reader_with_capacity_zero() {
+    let fmt = FileFormat::from_reader_with_capacity(std::io::empty(), 0).unwrap();
+    assert_eq!(fmt, FileFormat::Empty);
+    let fmt = FileFormat::from_reader_with_capacity(Cursor::new(b"Hello"), 0).unwrap();
+    assert_ne!(fmt, FileFormat::Empty);
+}
+
+#[test]
+fn test_looks_like_text_utf8() {
+    assert!(FileFormat::looks_like_text(
+        "Ünïcödé text\r\n\twith whitespaces\n".as_bytes()
+    ));
+}
+
+#[test]
+fn test_looks_like_text_binary() {
+    let bytes = std::fs::read("fixtures/image/sample.png").unwrap();
+    assert!(!FileFormat::looks_like_text(&bytes));
+    assert!(!FileFormat::looks_like_text(b""));
+}
+
+#[test]
+fn test_looks_like_text_mixed() {
+    let mut bytes = b"Mostly text ".repeat(10);
+    bytes.push(0x01);
+    assert!(FileFormat::looks_like_text(&bytes));
+    bytes.extend([0x01; 10]);
+    assert!(!FileFormat::looks_like_text(&bytes));
+    assert!(!FileFormat::looks_like_text(b"Text with a NUL\0 byte"));
+}
+
+#[test]
+fn test_hash() {
+    let mut set = std::collections::HashSet::new();
+    set.insert(FileFormat::Zip);
+    set.insert(FileFormat::Zip);
+    set.insert(FileFormat::Abiword);
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_from_paths() {
+    let results = FileFormat::from_paths(vec![
+        PathBuf::from("fixtures/image/sample.png"),
+        PathBuf::from("fixtures/image/missing.png"),
+        PathBuf::from("fixtures/video/sample.avi"),
+    ]);
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].0, PathBuf::from("fixtures/image/sample.png"));
+    assert_eq!(
+        results[0].1.as_ref().ok(),
+        Some(&FileFormat::PortableNetworkGraphics)
+    );
+    assert_eq!(results[1].0, PathBuf::from("fixtures/image/missing.png"));
+    assert_eq!(
+        results[1].1.as_ref().map_err(|error| error.kind()).err(),
+        Some(ErrorKind::NotFound)
+    );
+    assert_eq!(
+        results[2].1.as_ref().ok(),
+        Some(&FileFormat::AudioVideoInterleave)
+    );
+}
+
+#[test]
+fn test_from_paths_empty() {
+    assert!(FileFormat::from_paths(Vec::new()).is_empty());
+}
+
+#[test]
+fn test_from_reader_reads_signature_ranges_only() {
+    let mut data = b"\x89PNG\r\n\x1A\n".to_vec();
+    data.resize(65_536, 0);
+    let mut reader = CountingReader {
+        inner: Cursor::new(data),
+        bytes_read: 0,
+    };
+    let fmt = FileFormat::from_reader(&mut reader).unwrap();
+    assert_eq!(fmt, FileFormat::PortableNetworkGraphics);
+    assert!(reader.bytes_read < 1024, "{} bytes read", reader.bytes_read);
+}
+
+#[test]
+fn test_from_reader_skips_encoding_for_text() {
+    let mut data = b"#!/bin/sh\n".to_vec();
+    data.resize(65_536, b'a');
+    let mut reader = CountingReader {
+        inner: Cursor::new(data),
+        bytes_read: 0,
+    };
+    let fmt = FileFormat::from_reader(&mut reader).unwrap();
+    assert_eq!(fmt, FileFormat::ShellScript);
+    assert!(reader.bytes_read < 1024, "{} bytes read", reader.bytes_read);
+}
+
+#[test]
+fn test_report_reads_whole_buffer_for_text() {
+    let mut data = b"#!/bin/sh\n".to_vec();
+    data.resize(65_536, b'a');
+    let mut reader = CountingReader {
+        inner: Cursor::new(data),
+        bytes_read: 0,
+    };
+    let report = Detector::new().report(&mut reader).unwrap();
+    assert!(report.encoding().is_some());
+    assert!(reader.bytes_read >= 36_870);
+}
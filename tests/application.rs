@@ -1,5 +1,12 @@
 use file_format::FileFormat;
 
+#[cfg(feature = "reader-asn1")]
+#[test]
+fn test_abstract_syntax_notation_one() {
+    let fmt = FileFormat::from_file("fixtures/application/sample.der").unwrap();
+    assert_eq!(fmt, FileFormat::AbstractSyntaxNotationOne);
+}
+
 #[test]
 fn test_activemime() {
     let fmt = FileFormat::from_file("fixtures/application/sample.mso").unwrap();
@@ -30,6 +37,20 @@ fn test_apache_arrow_columnar() {
     assert_eq!(fmt, FileFormat::ApacheArrowColumnar);
 }
 
+#[cfg(feature = "reader-arrow")]
+#[test]
+fn test_apache_arrow_stream() {
+    let fmt = FileFormat::from_file("fixtures/application/sample.arrows").unwrap();
+    assert_eq!(fmt, FileFormat::ApacheArrowStream);
+}
+
+#[cfg(feature = "reader-arrow")]
+#[test]
+fn test_apache_arrow_stream_invalid() {
+    let fmt = FileFormat::from_bytes(b"\xFF\xFF\xFF\xFF\x08\0\0\0\x04\0\0\0\0\0\0\0");
+    assert_ne!(fmt, FileFormat::ApacheArrowStream);
+}
+
 #[test]
 fn test_apache_avro_object_container() {
     let fmt = FileFormat::from_file("fixtures/application/sample.avro").unwrap();
@@ -44,7 +65,21 @@ fn test_apache_parquet() {
 
 #[test]
 fn test_arbitrary_binary_data() {
-    let fmt = FileFormat::from_file("fixtures/application/sample.bin").unwrap();
+    let fmt = FileFormat::from_file("fixtures/application/sample1.bin").unwrap();
+    assert_eq!(fmt, FileFormat::ArbitraryBinaryData);
+}
+
+#[cfg(feature = "reader-bson")]
+#[test]
+fn test_binary_json() {
+    let fmt = FileFormat::from_file("fixtures/application/sample.bson").unwrap();
+    assert_eq!(fmt, FileFormat::BinaryJson);
+}
+
+#[cfg(feature = "reader-bson")]
+#[test]
+fn test_binary_json_invalid() {
+    let fmt = FileFormat::from_bytes(b"\x0C\0\0\0\x42key\0\0\0\0");
     assert_eq!(fmt, FileFormat::ArbitraryBinaryData);
 }
 
@@ -54,6 +89,20 @@ fn test_bittorrent_file() {
     assert_eq!(fmt, FileFormat::BittorrentFile);
 }
 
+#[cfg(feature = "reader-capnp")]
+#[test]
+fn test_capn_proto_1() {
+    let fmt = FileFormat::from_file("fixtures/application/sample2.bin").unwrap();
+    assert_eq!(fmt, FileFormat::CapnProto);
+}
+
+#[cfg(feature = "reader-capnp")]
+#[test]
+fn test_capn_proto_2() {
+    let fmt = FileFormat::from_file("fixtures/application/sample3.bin").unwrap();
+    assert_eq!(fmt, FileFormat::CapnProto);
+}
+
 #[test]
 fn test_cd_audio() {
     let fmt = FileFormat::from_file("fixtures/application/sample.cda").unwrap();
@@ -103,6 +152,19 @@ fn test_extensible_stylesheet_language_transformations_2() {
     assert_eq!(fmt, FileFormat::ExtensibleStylesheetLanguageTransformations);
 }
 
+#[test]
+fn test_feather_v1() {
+    let fmt = FileFormat::from_file("fixtures/application/sample.feather").unwrap();
+    assert_eq!(fmt, FileFormat::FeatherV1);
+}
+
+#[cfg(feature = "reader-flatbuffers")]
+#[test]
+fn test_flatbuffers() {
+    let fmt = FileFormat::from_file("fixtures/application/sample4.bin").unwrap();
+    assert_eq!(fmt, FileFormat::Flatbuffers);
+}
+
 #[test]
 fn test_flexible_image_transport_system() {
     let fmt = FileFormat::from_file("fixtures/application/sample.fits").unwrap();
@@ -115,18 +177,67 @@ fn test_gettext_machine_object() {
     assert_eq!(fmt, FileFormat::GettextMachineObject);
 }
 
+#[cfg(feature = "reader-json")]
+#[test]
+fn test_http_archive() {
+    let fmt = FileFormat::from_file("fixtures/application/sample.har").unwrap();
+    assert_eq!(fmt, FileFormat::HttpArchive);
+}
+
+#[cfg(feature = "reader-json")]
+#[test]
+fn test_http_archive_invalid() {
+    let fmt = FileFormat::from_bytes(b"{\"log\": {\"level\": \"debug\", \"entries\": []}}");
+    assert_ne!(fmt, FileFormat::HttpArchive);
+}
+
 #[test]
 fn test_icc_profile() {
     let fmt = FileFormat::from_file("fixtures/application/sample.icc").unwrap();
     assert_eq!(fmt, FileFormat::IccProfile);
 }
 
+#[cfg(feature = "reader-iff")]
+#[test]
+fn test_interchange_file_format() {
+    let fmt = FileFormat::from_file("fixtures/application/sample.iff").unwrap();
+    assert_eq!(fmt, FileFormat::InterchangeFileFormat);
+}
+
+#[cfg(feature = "reader-iff")]
+#[test]
+fn test_interchange_file_format_invalid() {
+    let fmt = FileFormat::from_bytes(b"FORM\0\0\0\x04\x01\x02\x03\x04");
+    assert_ne!(fmt, FileFormat::InterchangeFileFormat);
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_interchange_file_format_letter() {
+    let fmt = FileFormat::from_bytes(b"FORM letter to the editor\nDear Sir or Madam,\n");
+    assert_eq!(fmt, FileFormat::PlainText);
+}
+
 #[test]
 fn test_java_keystore() {
     let fmt = FileFormat::from_file("fixtures/application/sample.jks").unwrap();
     assert_eq!(fmt, FileFormat::JavaKeystore);
 }
 
+#[cfg(feature = "reader-json")]
+#[test]
+fn test_json_web_key_set() {
+    let fmt = FileFormat::from_file("fixtures/application/sample.json").unwrap();
+    assert_eq!(fmt, FileFormat::JsonWebKeySet);
+}
+
+#[cfg(feature = "reader-json")]
+#[test]
+fn test_json_web_token() {
+    let fmt = FileFormat::from_file("fixtures/application/sample.jwt").unwrap();
+    assert_eq!(fmt, FileFormat::JsonWebToken);
+}
+
 #[test]
 fn test_macos_alias() {
     let fmt = FileFormat::from_file("fixtures/application/sample.alias").unwrap();
@@ -146,6 +257,12 @@ fn test_mathematical_markup_language_2() {
     assert_eq!(fmt, FileFormat::MathematicalMarkupLanguage);
 }
 
+#[test]
+fn test_matlab_data() {
+    let fmt = FileFormat::from_file("fixtures/application/sample.mat").unwrap();
+    assert_eq!(fmt, FileFormat::MatlabData);
+}
+
 #[test]
 fn test_meta_information_encapsulation() {
     let fmt = FileFormat::from_file("fixtures/application/sample.mie").unwrap();
@@ -197,14 +314,52 @@ fn test_ogg_multiplexed_media() {
 }
 
 #[test]
-fn test_pcap_dump() {
-    let fmt = FileFormat::from_file("fixtures/application/sample.pcap").unwrap();
+fn test_openssh_private_key() {
+    let fmt = FileFormat::from_file("fixtures/application/sample2.key").unwrap();
+    assert_eq!(fmt, FileFormat::OpensshPrivateKey);
+}
+
+#[test]
+fn test_openssh_public_key() {
+    let fmt = FileFormat::from_file("fixtures/application/sample2.pub").unwrap();
+    assert_eq!(fmt, FileFormat::OpensshPublicKey);
+}
+
+#[cfg(feature = "reader-orc")]
+#[test]
+fn test_optimized_row_columnar() {
+    let fmt = FileFormat::from_file("fixtures/application/sample.orc").unwrap();
+    assert_eq!(fmt, FileFormat::OptimizedRowColumnar);
+}
+
+#[cfg(feature = "reader-orc")]
+#[test]
+fn test_optimized_row_columnar_invalid() {
+    let fmt = FileFormat::from_bytes(b"ORCA whale\n\x04");
+    assert_ne!(fmt, FileFormat::OptimizedRowColumnar);
+}
+
+#[test]
+fn test_pcap_dump_1() {
+    let fmt = FileFormat::from_file("fixtures/application/sample1.pcap").unwrap();
+    assert_eq!(fmt, FileFormat::PcapDump);
+}
+
+#[test]
+fn test_pcap_dump_2() {
+    let fmt = FileFormat::from_file("fixtures/application/sample2.pcap").unwrap();
     assert_eq!(fmt, FileFormat::PcapDump);
 }
 
 #[test]
-fn test_pcap_next_generation_dump() {
-    let fmt = FileFormat::from_file("fixtures/application/sample.pcapng").unwrap();
+fn test_pcap_next_generation_dump_1() {
+    let fmt = FileFormat::from_file("fixtures/application/sample1.pcapng").unwrap();
+    assert_eq!(fmt, FileFormat::PcapNextGenerationDump);
+}
+
+#[test]
+fn test_pcap_next_generation_dump_2() {
+    let fmt = FileFormat::from_file("fixtures/application/sample2.pcapng").unwrap();
     assert_eq!(fmt, FileFormat::PcapNextGenerationDump);
 }
 
@@ -216,13 +371,13 @@ fn test_pem_certificate_signing_request() {
 
 #[test]
 fn test_pem_private_key() {
-    let fmt = FileFormat::from_file("fixtures/application/sample.key").unwrap();
+    let fmt = FileFormat::from_file("fixtures/application/sample1.key").unwrap();
     assert_eq!(fmt, FileFormat::PemPrivateKey);
 }
 
 #[test]
 fn test_pem_public_key() {
-    let fmt = FileFormat::from_file("fixtures/application/sample.pub").unwrap();
+    let fmt = FileFormat::from_file("fixtures/application/sample1.pub").unwrap();
     assert_eq!(fmt, FileFormat::PemPublicKey);
 }
 
@@ -268,12 +423,30 @@ fn test_postscript() {
     assert_eq!(fmt, FileFormat::Postscript);
 }
 
+#[test]
+fn test_r_data() {
+    let fmt = FileFormat::from_file("fixtures/application/sample.rdata").unwrap();
+    assert_eq!(fmt, FileFormat::RData);
+}
+
+#[test]
+fn test_r_data_serialization() {
+    let fmt = FileFormat::from_file("fixtures/application/sample1.rds").unwrap();
+    assert_eq!(fmt, FileFormat::RDataSerialization);
+}
+
 #[test]
 fn test_realmedia() {
     let fmt = FileFormat::from_file("fixtures/application/sample.rm").unwrap();
     assert_eq!(fmt, FileFormat::Realmedia);
 }
 
+#[test]
+fn test_sas_data() {
+    let fmt = FileFormat::from_file("fixtures/application/sample.sas7bdat").unwrap();
+    assert_eq!(fmt, FileFormat::SasData);
+}
+
 #[test]
 fn test_simple_object_access_protocol_1() {
     let fmt = FileFormat::from_file("fixtures/application/sample1.soap").unwrap();
@@ -293,12 +466,50 @@ fn test_small_web_format() {
     assert_eq!(fmt, FileFormat::SmallWebFormat);
 }
 
+#[test]
+fn test_spss_data() {
+    let fmt = FileFormat::from_file("fixtures/application/sample.sav").unwrap();
+    assert_eq!(fmt, FileFormat::SpssData);
+}
+
+#[test]
+fn test_stata_data_1() {
+    let fmt = FileFormat::from_file("fixtures/application/sample1.dta").unwrap();
+    assert_eq!(fmt, FileFormat::StataData);
+}
+
+#[test]
+fn test_stata_data_2() {
+    let fmt = FileFormat::from_file("fixtures/application/sample2.dta").unwrap();
+    assert_eq!(fmt, FileFormat::StataData);
+}
+
+#[test]
+fn test_systemd_journal() {
+    let fmt = FileFormat::from_file("fixtures/application/sample.journal").unwrap();
+    assert_eq!(fmt, FileFormat::SystemdJournal);
+}
+
 #[test]
 fn test_tasty() {
     let fmt = FileFormat::from_file("fixtures/application/sample.tasty").unwrap();
     assert_eq!(fmt, FileFormat::Tasty);
 }
 
+#[cfg(feature = "reader-thrift")]
+#[test]
+fn test_thrift_1() {
+    let fmt = FileFormat::from_file("fixtures/application/sample5.bin").unwrap();
+    assert_eq!(fmt, FileFormat::Thrift);
+}
+
+#[cfg(feature = "reader-thrift")]
+#[test]
+fn test_thrift_2() {
+    let fmt = FileFormat::from_file("fixtures/application/sample6.bin").unwrap();
+    assert_eq!(fmt, FileFormat::Thrift);
+}
+
 #[test]
 fn test_tiled_map_xml_1() {
     let fmt = FileFormat::from_file("fixtures/application/sample1.tmx").unwrap();
@@ -325,12 +536,26 @@ fn test_tiled_tileset_xml_2() {
     assert_eq!(fmt, FileFormat::TiledTilesetXml);
 }
 
+#[cfg(feature = "reader-cfb")]
+#[test]
+fn test_windows_jump_list() {
+    let fmt =
+        FileFormat::from_file("fixtures/application/sample.automaticDestinations-ms").unwrap();
+    assert_eq!(fmt, FileFormat::WindowsJumpList);
+}
+
 #[test]
 fn test_windows_shortcut() {
     let fmt = FileFormat::from_file("fixtures/application/sample.lnk").unwrap();
     assert_eq!(fmt, FileFormat::WindowsShortcut);
 }
 
+#[test]
+fn test_windows_thumbcache() {
+    let fmt = FileFormat::from_file("fixtures/application/sample.db").unwrap();
+    assert_eq!(fmt, FileFormat::WindowsThumbcache);
+}
+
 #[test]
 fn test_xml_localization_interchange_file_format_1() {
     let fmt = FileFormat::from_file("fixtures/application/sample1.xlf").unwrap();
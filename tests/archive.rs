@@ -20,22 +20,54 @@ fn test_archived_by_robert_jung() {
 
 #[test]
 fn test_cabinet() {
-    let fmt = FileFormat::from_file("fixtures/archive/sample.cab").unwrap();
+    let fmt = FileFormat::from_file("fixtures/archive/sample1.cab").unwrap();
     assert_eq!(fmt, FileFormat::Cabinet);
 }
 
+#[cfg(feature = "decompress-gzip")]
+#[test]
+fn test_compressed_tape() {
+    let fmt = FileFormat::from_file("fixtures/archive/sample.tgz").unwrap();
+    assert_eq!(fmt, FileFormat::CompressedTape);
+}
+
+#[test]
+fn test_compressed_tape_invalid() {
+    let fmt = FileFormat::from_file("fixtures/compression/sample4.gz").unwrap();
+    assert_ne!(fmt, FileFormat::CompressedTape);
+}
+
 #[test]
 fn test_cpio() {
     let fmt = FileFormat::from_file("fixtures/archive/sample.cpio").unwrap();
     assert_eq!(fmt, FileFormat::Cpio);
 }
 
+#[cfg(feature = "reader-tar")]
+#[test]
+fn test_docker_image() {
+    let fmt = FileFormat::from_file("fixtures/archive/sample2.tar").unwrap();
+    assert_eq!(fmt, FileFormat::DockerImage);
+}
+
+#[test]
+fn test_electronic_software_download() {
+    let fmt = FileFormat::from_file("fixtures/archive/sample.esd").unwrap();
+    assert_eq!(fmt, FileFormat::ElectronicSoftwareDownload);
+}
+
 #[test]
 fn test_extensible_archive() {
     let fmt = FileFormat::from_file("fixtures/archive/sample.xar").unwrap();
     assert_eq!(fmt, FileFormat::ExtensibleArchive);
 }
 
+#[test]
+fn test_installshield_cabinet() {
+    let fmt = FileFormat::from_file("fixtures/archive/sample2.cab").unwrap();
+    assert_eq!(fmt, FileFormat::InstallshieldCabinet);
+}
+
 #[test]
 fn test_larc() {
     let fmt = FileFormat::from_file("fixtures/archive/sample.lzs").unwrap();
@@ -48,12 +80,32 @@ fn test_lha() {
     assert_eq!(fmt, FileFormat::Lha);
 }
 
+#[cfg(feature = "reader-tar")]
+#[test]
+fn test_oci_image_layout() {
+    let fmt = FileFormat::from_file("fixtures/archive/sample3.tar").unwrap();
+    assert_eq!(fmt, FileFormat::OciImageLayout);
+}
+
+#[cfg(feature = "reader-tar")]
+#[test]
+fn test_oci_image_layout_base256_size() {
+    let fmt = FileFormat::from_file("fixtures/archive/sample4.tar").unwrap();
+    assert_eq!(fmt, FileFormat::OciImageLayout);
+}
+
 #[test]
 fn test_pmarc() {
     let fmt = FileFormat::from_file("fixtures/archive/sample.pma").unwrap();
     assert_eq!(fmt, FileFormat::Pmarc);
 }
 
+#[test]
+fn test_renpy_archive() {
+    let fmt = FileFormat::from_file("fixtures/archive/sample.rpa").unwrap();
+    assert_eq!(fmt, FileFormat::RenpyArchive);
+}
+
 #[test]
 fn test_roshal_archive() {
     let fmt = FileFormat::from_file("fixtures/archive/sample.rar").unwrap();
@@ -67,8 +119,14 @@ fn test_seqbox() {
 }
 
 #[test]
-fn test_seven_zip() {
-    let fmt = FileFormat::from_file("fixtures/archive/sample.7z").unwrap();
+fn test_seven_zip_1() {
+    let fmt = FileFormat::from_file("fixtures/archive/sample1.7z").unwrap();
+    assert_eq!(fmt, FileFormat::SevenZip);
+}
+
+#[test]
+fn test_seven_zip_2() {
+    let fmt = FileFormat::from_file("fixtures/archive/sample2.7z").unwrap();
     assert_eq!(fmt, FileFormat::SevenZip);
 }
 
@@ -92,10 +150,16 @@ fn test_stuffit_x() {
 
 #[test]
 fn test_tape_archive() {
-    let fmt = FileFormat::from_file("fixtures/archive/sample.tar").unwrap();
+    let fmt = FileFormat::from_file("fixtures/archive/sample1.tar").unwrap();
     assert_eq!(fmt, FileFormat::TapeArchive);
 }
 
+#[test]
+fn test_unity_asset_bundle() {
+    let fmt = FileFormat::from_file("fixtures/archive/sample.unity3d").unwrap();
+    assert_eq!(fmt, FileFormat::UnityAssetBundle);
+}
+
 #[test]
 fn test_unix_archiver() {
     let fmt = FileFormat::from_file("fixtures/archive/sample.a").unwrap();
@@ -109,8 +173,15 @@ fn test_windows_imaging_format() {
 }
 
 #[test]
-fn test_zip() {
-    let fmt = FileFormat::from_file("fixtures/archive/sample.zip").unwrap();
+fn test_zip_1() {
+    let fmt = FileFormat::from_file("fixtures/archive/sample1.zip").unwrap();
+    assert_eq!(fmt, FileFormat::Zip);
+}
+
+#[cfg(feature = "reader-zip")]
+#[test]
+fn test_zip_2() {
+    let fmt = FileFormat::from_file("fixtures/archive/sample2.zip").unwrap();
     assert_eq!(fmt, FileFormat::Zip);
 }
 
@@ -60,18 +60,36 @@ fn test_audio_interchange_file_format() {
     assert_eq!(fmt, FileFormat::AudioInterchangeFileFormat);
 }
 
+#[test]
+fn test_audio_interchange_file_format_compressed() {
+    let fmt = FileFormat::from_file("fixtures/audio/sample.aifc").unwrap();
+    assert_eq!(fmt, FileFormat::AudioInterchangeFileFormatCompressed);
+}
+
 #[test]
 fn test_audio_visual_research() {
     let fmt = FileFormat::from_file("fixtures/audio/sample.avr").unwrap();
     assert_eq!(fmt, FileFormat::AudioVisualResearch);
 }
 
+#[test]
+fn test_core_audio_format() {
+    let fmt = FileFormat::from_file("fixtures/audio/sample.caf").unwrap();
+    assert_eq!(fmt, FileFormat::CoreAudioFormat);
+}
+
 #[test]
 fn test_creative_voice() {
     let fmt = FileFormat::from_file("fixtures/audio/sample.voc").unwrap();
     assert_eq!(fmt, FileFormat::CreativeVoice);
 }
 
+#[test]
+fn test_downloadable_sounds() {
+    let fmt = FileFormat::from_file("fixtures/audio/sample.dls").unwrap();
+    assert_eq!(fmt, FileFormat::DownloadableSounds);
+}
+
 #[test]
 fn test_fasttracker2_extended_module() {
     let fmt = FileFormat::from_file("fixtures/audio/sample.xm").unwrap();
@@ -103,6 +121,13 @@ fn test_matroska_audio() {
     assert_eq!(fmt, FileFormat::MatroskaAudio);
 }
 
+#[cfg(feature = "reader-midi")]
+#[test]
+fn test_midi_karaoke() {
+    let fmt = FileFormat::from_file("fixtures/audio/sample.kar").unwrap();
+    assert_eq!(fmt, FileFormat::MidiKaraoke);
+}
+
 #[test]
 fn test_monkeys_audio() {
     let fmt = FileFormat::from_file("fixtures/audio/sample.ape").unwrap();
@@ -140,6 +165,12 @@ fn test_musical_instrument_digital_interface() {
     assert_eq!(fmt, FileFormat::MusicalInstrumentDigitalInterface);
 }
 
+#[test]
+fn test_musical_instrument_digital_interface_short_header() {
+    let fmt = FileFormat::from_bytes(b"MThd\0\0\0\x02\0\0");
+    assert_eq!(fmt, FileFormat::MusicalInstrumentDigitalInterface);
+}
+
 #[test]
 fn test_ogg_flac() {
     let fmt = FileFormat::from_file("fixtures/audio/sample.oga").unwrap();
@@ -11,3 +11,15 @@ fn test_pem_certificate() {
     let fmt = FileFormat::from_file("fixtures/certificate/sample.crt").unwrap();
     assert_eq!(fmt, FileFormat::PemCertificate);
 }
+
+#[test]
+fn test_pkcs12() {
+    let fmt = FileFormat::from_file("fixtures/certificate/sample.p12").unwrap();
+    assert_eq!(fmt, FileFormat::Pkcs12);
+}
+
+#[test]
+fn test_pkcs7() {
+    let fmt = FileFormat::from_file("fixtures/certificate/sample.p7b").unwrap();
+    assert_eq!(fmt, FileFormat::Pkcs7);
+}
@@ -12,6 +12,12 @@ fn test_bzip2() {
     assert_eq!(fmt, FileFormat::Bzip2);
 }
 
+#[test]
+fn test_bzip2_invalid() {
+    let data = b"BZh9 is not a bzip2 stream";
+    assert_ne!(FileFormat::from_bytes(data), FileFormat::Bzip2);
+}
+
 #[test]
 fn test_bzip3() {
     let fmt = FileFormat::from_file("fixtures/compression/sample.bz3").unwrap();
@@ -20,7 +26,7 @@ fn test_bzip3() {
 
 #[test]
 fn test_gzip() {
-    let fmt = FileFormat::from_file("fixtures/compression/sample.gz").unwrap();
+    let fmt = FileFormat::from_file("fixtures/compression/sample1.gz").unwrap();
     assert_eq!(fmt, FileFormat::Gzip);
 }
 
@@ -43,11 +49,24 @@ fn test_long_range_zip() {
 }
 
 #[test]
-fn test_lz4() {
-    let fmt = FileFormat::from_file("fixtures/compression/sample.lz4").unwrap();
+fn test_lz4_1() {
+    let fmt = FileFormat::from_file("fixtures/compression/sample1.lz4").unwrap();
     assert_eq!(fmt, FileFormat::Lz4);
 }
 
+#[cfg(feature = "reader-zstd")]
+#[test]
+fn test_lz4_2() {
+    let fmt = FileFormat::from_file("fixtures/compression/sample3.lz4").unwrap();
+    assert_eq!(fmt, FileFormat::Lz4);
+}
+
+#[test]
+fn test_lz4_legacy() {
+    let fmt = FileFormat::from_file("fixtures/compression/sample2.lz4").unwrap();
+    assert_eq!(fmt, FileFormat::Lz4Legacy);
+}
+
 #[test]
 fn test_lzip() {
     let fmt = FileFormat::from_file("fixtures/compression/sample.lz").unwrap();
@@ -60,6 +79,18 @@ fn test_lzop() {
     assert_eq!(fmt, FileFormat::Lzop);
 }
 
+#[test]
+fn test_ms_compress_kwaj() {
+    let fmt = FileFormat::from_file("fixtures/compression/sample2._").unwrap();
+    assert_eq!(fmt, FileFormat::MsCompressKwaj);
+}
+
+#[test]
+fn test_ms_compress_szdd() {
+    let fmt = FileFormat::from_file("fixtures/compression/sample1._").unwrap();
+    assert_eq!(fmt, FileFormat::MsCompressSzdd);
+}
+
 #[test]
 fn test_rzip() {
     let fmt = FileFormat::from_file("fixtures/compression/sample.rz").unwrap();
@@ -79,13 +110,61 @@ fn test_unix_compress() {
 }
 
 #[test]
-fn test_xz() {
-    let fmt = FileFormat::from_file("fixtures/compression/sample.xz").unwrap();
+fn test_xz_1() {
+    let fmt = FileFormat::from_file("fixtures/compression/sample1.xz").unwrap();
     assert_eq!(fmt, FileFormat::Xz);
 }
 
 #[test]
-fn test_zstandard() {
-    let fmt = FileFormat::from_file("fixtures/compression/sample.zst").unwrap();
+fn test_xz_2() {
+    let fmt = FileFormat::from_file("fixtures/compression/sample2.xz").unwrap();
+    assert_eq!(fmt, FileFormat::Xz);
+}
+
+#[test]
+fn test_xz_3() {
+    let fmt = FileFormat::from_file("fixtures/compression/sample3.xz").unwrap();
+    assert_eq!(fmt, FileFormat::Xz);
+}
+
+#[test]
+fn test_xz_4() {
+    let fmt = FileFormat::from_file("fixtures/compression/sample4.xz").unwrap();
+    assert_eq!(fmt, FileFormat::Xz);
+}
+
+#[cfg(feature = "reader-xz")]
+#[test]
+fn test_xz_invalid() {
+    let mut data = std::fs::read("fixtures/compression/sample1.xz").unwrap();
+    data[8] ^= 0xFF;
+    assert_ne!(FileFormat::from_bytes(&data), FileFormat::Xz);
+}
+
+#[cfg(feature = "reader-xz")]
+#[test]
+fn test_xz_invalid_footer() {
+    let mut data = std::fs::read("fixtures/compression/sample1.xz").unwrap();
+    let length = data.len();
+    data[length - 3] ^= 0xFF;
+    assert_ne!(FileFormat::from_bytes(&data), FileFormat::Xz);
+}
+
+#[test]
+fn test_xz_truncated() {
+    let mut data = std::fs::read("fixtures/compression/sample1.xz").unwrap();
+    data.truncate(data.len() - 20);
+    assert_eq!(FileFormat::from_bytes(&data), FileFormat::Xz);
+}
+
+#[test]
+fn test_zstandard_1() {
+    let fmt = FileFormat::from_file("fixtures/compression/sample1.zst").unwrap();
+    assert_eq!(fmt, FileFormat::Zstandard);
+}
+
+#[test]
+fn test_zstandard_2() {
+    let fmt = FileFormat::from_file("fixtures/compression/sample2.zst").unwrap();
     assert_eq!(fmt, FileFormat::Zstandard);
 }
@@ -1,5 +1,17 @@
 use file_format::FileFormat;
 
+#[test]
+fn test_duckdb_database() {
+    let fmt = FileFormat::from_file("fixtures/database/sample.duckdb").unwrap();
+    assert_eq!(fmt, FileFormat::DuckdbDatabase);
+}
+
+#[test]
+fn test_duckdb_database_invalid() {
+    let fmt = FileFormat::from_bytes(b"\0\0\0\0\0\0\0\0DUCK\x40\0\xFF\xFF\xFF\xFF\xFF\xFF");
+    assert_ne!(fmt, FileFormat::DuckdbDatabase);
+}
+
 #[test]
 fn test_microsoft_access2007_database() {
     let fmt = FileFormat::from_file("fixtures/database/sample.accdb").unwrap();
@@ -32,6 +44,24 @@ fn test_opendocument_database() {
     assert_eq!(fmt, FileFormat::OpendocumentDatabase);
 }
 
+#[test]
+fn test_prometheus_chunks() {
+    let fmt = FileFormat::from_file("fixtures/database/sample.chunks").unwrap();
+    assert_eq!(fmt, FileFormat::PrometheusChunks);
+}
+
+#[test]
+fn test_prometheus_index() {
+    let fmt = FileFormat::from_file("fixtures/database/sample.index").unwrap();
+    assert_eq!(fmt, FileFormat::PrometheusIndex);
+}
+
+#[test]
+fn test_redis_database() {
+    let fmt = FileFormat::from_file("fixtures/database/sample.rdb").unwrap();
+    assert_eq!(fmt, FileFormat::RedisDatabase);
+}
+
 #[test]
 fn test_sqlite3() {
     let fmt = FileFormat::from_file("fixtures/database/sample.sqlite").unwrap();
@@ -0,0 +1,482 @@
+#[cfg(feature = "reader-xz")]
+use file_format::IntegrityCheck;
+use file_format::{ByteOrder, Detector, FileFormat, Kind, TextEncoding};
+use std::{fs::File, io::Cursor};
+
+#[test]
+fn test_loose_text_ascii() {
+    let mut detector = Detector::new().loose_text(true);
+    let fmt = detector.detect(Cursor::new(b"Hello, world!\n")).unwrap();
+    assert_eq!(fmt, FileFormat::PlainText);
+}
+
+#[test]
+fn test_loose_text_invalid_utf8() {
+    let mut detector = Detector::new().loose_text(true);
+    let fmt = detector.detect(Cursor::new(b"caf\x81 au lait\n")).unwrap();
+    assert_eq!(fmt, FileFormat::PlainText);
+}
+
+#[test]
+fn test_loose_text_few_control_characters() {
+    let mut bytes = b"Lorem ipsum dolor sit amet, consectetur adipiscing elit. ".repeat(4);
+    bytes[16] = 0x07;
+    let mut detector = Detector::new().loose_text(true);
+    let fmt = detector.detect(Cursor::new(bytes)).unwrap();
+    assert_eq!(fmt, FileFormat::PlainText);
+}
+
+#[test]
+fn test_loose_text_many_control_characters() {
+    let bytes = b"\x01\x02\x03\x04text\x05\x06\x07\x08".repeat(8);
+    let mut detector = Detector::new().loose_text(true);
+    let fmt = detector.detect(Cursor::new(bytes)).unwrap();
+    assert_eq!(fmt, FileFormat::ArbitraryBinaryData);
+}
+
+#[test]
+fn test_loose_text_nul_byte() {
+    let mut detector = Detector::new().loose_text(true);
+    let fmt = detector.detect(Cursor::new(b"Hello,\0world!\n")).unwrap();
+    assert_eq!(fmt, FileFormat::ArbitraryBinaryData);
+}
+
+#[test]
+fn test_loose_text_signature() {
+    let mut detector = Detector::new().loose_text(true);
+    let fmt = detector.detect(Cursor::new(b"%PDF-1.7\n")).unwrap();
+    assert_eq!(fmt, FileFormat::PortableDocumentFormat);
+}
+
+#[cfg(not(feature = "reader-txt"))]
+#[test]
+fn test_strict_text_disabled() {
+    let fmt = Detector::new()
+        .detect(Cursor::new(b"Hello, world!\n"))
+        .unwrap();
+    assert_eq!(fmt, FileFormat::ArbitraryBinaryData);
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_strict_text_ascii() {
+    let fmt = Detector::new()
+        .detect(Cursor::new(b"Hello, world!\n"))
+        .unwrap();
+    assert_eq!(fmt, FileFormat::PlainText);
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_strict_text_invalid_utf8() {
+    let fmt = Detector::new()
+        .detect(Cursor::new(b"caf\x81 au lait\n"))
+        .unwrap();
+    assert_eq!(fmt, FileFormat::ArbitraryBinaryData);
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_strict_text_few_control_characters() {
+    let mut bytes = b"Lorem ipsum dolor sit amet, consectetur adipiscing elit. ".repeat(4);
+    bytes[16] = 0x07;
+    let fmt = Detector::new().detect(Cursor::new(bytes)).unwrap();
+    assert_eq!(fmt, FileFormat::ArbitraryBinaryData);
+}
+
+#[test]
+fn test_encoding_utf8() {
+    let file = File::open("fixtures/text/sample1.txt").unwrap();
+    let report = Detector::new().loose_text(true).report(file).unwrap();
+    assert_eq!(report.format(), FileFormat::PlainText);
+    assert_eq!(report.encoding(), Some(TextEncoding::Utf8));
+}
+
+#[test]
+fn test_encoding_latin1() {
+    let file = File::open("fixtures/text/sample2.txt").unwrap();
+    let report = Detector::new().loose_text(true).report(file).unwrap();
+    assert_eq!(report.format(), FileFormat::PlainText);
+    assert_eq!(report.encoding(), Some(TextEncoding::Latin1));
+}
+
+#[test]
+fn test_encoding_windows_1252() {
+    let file = File::open("fixtures/text/sample3.txt").unwrap();
+    let report = Detector::new().loose_text(true).report(file).unwrap();
+    assert_eq!(report.format(), FileFormat::PlainText);
+    assert_eq!(report.encoding(), Some(TextEncoding::Windows1252));
+}
+
+#[test]
+fn test_encoding_signature() {
+    let report = Detector::new()
+        .report(Cursor::new(b"#!/bin/sh\necho caf\xE9\n"))
+        .unwrap();
+    assert_eq!(report.format(), FileFormat::ShellScript);
+    assert_eq!(report.encoding(), Some(TextEncoding::Latin1));
+}
+
+#[test]
+fn test_encoding_binary() {
+    let report = Detector::new()
+        .report(Cursor::new(b"\x89PNG\r\n\x1A\n"))
+        .unwrap();
+    assert_eq!(report.format(), FileFormat::PortableNetworkGraphics);
+    assert_eq!(report.encoding(), None);
+}
+
+#[test]
+fn test_offset_start() {
+    let file = File::open("fixtures/image/sample.png").unwrap();
+    let report = Detector::new().report(file).unwrap();
+    assert_eq!(report.format(), FileFormat::PortableNetworkGraphics);
+    assert_eq!(report.offset(), Some(0));
+}
+
+#[test]
+fn test_offset_nonzero() {
+    let file = File::open("fixtures/archive/sample1.tar").unwrap();
+    let report = Detector::new().report(file).unwrap();
+    assert_eq!(report.format(), FileFormat::TapeArchive);
+    assert_eq!(report.offset(), Some(257));
+}
+
+#[test]
+fn test_offset_unknown() {
+    let report = Detector::new()
+        .report(Cursor::new([0xDE, 0xAD, 0xBE, 0xEF, 0x00]))
+        .unwrap();
+    assert_eq!(report.format(), FileFormat::ArbitraryBinaryData);
+    assert_eq!(report.offset(), None);
+}
+
+#[test]
+fn test_payload_self_extracting_zip() {
+    let file = File::open("fixtures/executable/sample5.exe").unwrap();
+    let report = Detector::new().payload(true).report(file).unwrap();
+    assert_eq!(report.format().kind(), Kind::Executable);
+    assert_eq!(report.payload(), Some(FileFormat::Zip));
+}
+
+#[test]
+fn test_payload_pe_section() {
+    // Adds a section holding a ZIP local file header, which is not an overlay.
+    let mut bytes = std::fs::read("fixtures/executable/sample4.exe").unwrap();
+    bytes[70] = 1;
+    bytes.extend(b".rdata\0\0\0\0\0\0\0\0\0\0\x40\0\0\0\x80\0\0\0");
+    bytes.resize(128, 0);
+    bytes.extend(b"PK\x03\x04");
+    bytes.resize(192, 0);
+    let report = Detector::new()
+        .payload(true)
+        .report(Cursor::new(&bytes))
+        .unwrap();
+    assert_eq!(report.payload(), None);
+
+    // Appends the same header as an overlay.
+    bytes.extend(b"PK\x03\x04");
+    let report = Detector::new()
+        .payload(true)
+        .report(Cursor::new(&bytes))
+        .unwrap();
+    assert_eq!(report.payload(), Some(FileFormat::Zip));
+}
+
+#[test]
+fn test_payload_disabled() {
+    let file = File::open("fixtures/executable/sample5.exe").unwrap();
+    let report = Detector::new().report(file).unwrap();
+    assert_eq!(report.payload(), None);
+}
+
+#[test]
+fn test_payload_none() {
+    let file = File::open("fixtures/executable/sample4.exe").unwrap();
+    let report = Detector::new().payload(true).report(file).unwrap();
+    assert_eq!(report.payload(), None);
+}
+
+#[cfg(feature = "decompress-gzip")]
+#[test]
+fn test_inner_gzip_tar() {
+    let file = File::open("fixtures/compression/sample2.gz").unwrap();
+    let report = Detector::new().inner(true).report(file).unwrap();
+    assert_eq!(report.format(), FileFormat::CompressedTape);
+    assert_eq!(report.inner(), Some(FileFormat::TapeArchive));
+}
+
+#[cfg(all(feature = "decompress-gzip", feature = "reader-json"))]
+#[test]
+fn test_inner_gzip_json() {
+    let file = File::open("fixtures/compression/sample3.gz").unwrap();
+    let report = Detector::new().inner(true).report(file).unwrap();
+    assert_eq!(report.format(), FileFormat::Gzip);
+    assert_eq!(report.inner(), Some(FileFormat::JsonWebKeySet));
+}
+
+#[cfg(feature = "decompress-gzip")]
+#[test]
+fn test_inner_gzip_rds() {
+    let file = File::open("fixtures/application/sample2.rds").unwrap();
+    let report = Detector::new().inner(true).report(file).unwrap();
+    assert_eq!(report.format(), FileFormat::Gzip);
+    assert_eq!(report.inner(), Some(FileFormat::RDataSerialization));
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_inner_base64_png() {
+    let file = File::open("fixtures/text/sample1.b64").unwrap();
+    let report = Detector::new().inner(true).report(file).unwrap();
+    assert_eq!(report.format(), FileFormat::Base64);
+    assert_eq!(report.inner(), Some(FileFormat::PortableNetworkGraphics));
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_inner_base64_text() {
+    let file = File::open("fixtures/text/sample2.b64").unwrap();
+    let report = Detector::new().inner(true).report(file).unwrap();
+    assert_eq!(report.format(), FileFormat::Base64);
+    assert_eq!(report.inner(), Some(FileFormat::PlainText));
+}
+
+#[cfg(feature = "decompress-gzip")]
+#[test]
+fn test_inner_gzip_truncated() {
+    let file = File::open("fixtures/compression/sample1.gz").unwrap();
+    let report = Detector::new().inner(true).report(file).unwrap();
+    assert_eq!(report.inner(), None);
+}
+
+#[cfg(feature = "decompress-bzip2")]
+#[test]
+fn test_inner_bzip2() {
+    let file = File::open("fixtures/compression/sample.bz2").unwrap();
+    let report = Detector::new().inner(true).report(file).unwrap();
+    assert!(report.inner().is_some());
+}
+
+#[cfg(feature = "decompress-xz")]
+#[test]
+fn test_inner_xz() {
+    let file = File::open("fixtures/compression/sample1.xz").unwrap();
+    let report = Detector::new().inner(true).report(file).unwrap();
+    assert!(report.inner().is_some());
+}
+
+#[cfg(feature = "decompress-zstd")]
+#[test]
+fn test_inner_zstandard() {
+    let file = File::open("fixtures/compression/sample1.zst").unwrap();
+    let report = Detector::new().inner(true).report(file).unwrap();
+    assert!(report.inner().is_some());
+}
+
+#[test]
+fn test_inner_disabled() {
+    let file = File::open("fixtures/compression/sample2.gz").unwrap();
+    let report = Detector::new().report(file).unwrap();
+    assert_eq!(report.inner(), None);
+}
+
+#[cfg(feature = "reader-7z")]
+#[test]
+fn test_seven_zip_lzma2() {
+    let file = File::open("fixtures/archive/sample1.7z").unwrap();
+    let report = Detector::new().report(file).unwrap();
+    assert_eq!(
+        report.compression_method(),
+        Some(file_format::CompressionMethod::Lzma2)
+    );
+    assert!(!report.encrypted());
+}
+
+#[cfg(feature = "reader-7z")]
+#[test]
+fn test_seven_zip_encrypted_headers() {
+    let file = File::open("fixtures/archive/sample2.7z").unwrap();
+    let report = Detector::new().report(file).unwrap();
+    assert_eq!(report.compression_method(), None);
+    assert!(report.encrypted());
+}
+
+#[cfg(feature = "reader-7z")]
+#[test]
+fn test_seven_zip_header_offset_overflow() {
+    // Builds a start header whose next header offset overflows once added to its own size.
+    let mut bytes = b"7z\xBC\xAF\x27\x1C\0\x04\0\0\0\0".to_vec();
+    bytes.extend(0xFFFF_FFFF_FFFF_FFF0u64.to_le_bytes());
+    bytes.extend(1u64.to_le_bytes());
+    bytes.extend([0; 36]);
+    let report = Detector::new().report(Cursor::new(&bytes)).unwrap();
+    assert_eq!(report.format(), FileFormat::SevenZip);
+    assert_eq!(report.compression_method(), None);
+    assert_eq!(FileFormat::from_bytes(&bytes), FileFormat::SevenZip);
+}
+
+#[cfg(feature = "reader-7z")]
+#[test]
+fn test_seven_zip_coder_streams_overflow() {
+    // Builds an encoded header whose coders declare stream counts overflowing once summed.
+    let mut header = b"\x17\x07\x0B\x01\x00\x02\x11\x21\xFF".to_vec();
+    header.extend(u64::MAX.to_le_bytes());
+    header.extend(b"\x01\x11\x21\xFF");
+    header.extend(u64::MAX.to_le_bytes());
+    header.extend(b"\x01\x00");
+    let mut bytes = b"7z\xBC\xAF\x27\x1C\0\x04\0\0\0\0".to_vec();
+    bytes.extend(0u64.to_le_bytes());
+    bytes.extend((header.len() as u64).to_le_bytes());
+    bytes.extend([0; 4]);
+    bytes.extend(header);
+    let report = Detector::new().report(Cursor::new(&bytes)).unwrap();
+    assert_eq!(report.format(), FileFormat::SevenZip);
+    assert_eq!(report.compression_method(), None);
+}
+
+#[test]
+fn test_compression_method_unknown() {
+    let file = File::open("fixtures/image/sample.png").unwrap();
+    let report = Detector::new().report(file).unwrap();
+    assert_eq!(report.compression_method(), None);
+    assert!(!report.encrypted());
+}
+
+#[cfg(feature = "reader-xz")]
+#[test]
+fn test_integrity_check_xz() {
+    let checks = [
+        (
+            "fixtures/compression/sample1.xz",
+            Some(IntegrityCheck::Crc32),
+        ),
+        (
+            "fixtures/compression/sample2.xz",
+            Some(IntegrityCheck::Crc64),
+        ),
+        (
+            "fixtures/compression/sample3.xz",
+            Some(IntegrityCheck::Sha256),
+        ),
+        ("fixtures/compression/sample4.xz", None),
+    ];
+    for (path, check) in checks {
+        let report = Detector::new().report(File::open(path).unwrap()).unwrap();
+        assert_eq!(report.integrity_check(), check, "{}", path);
+    }
+}
+
+#[test]
+fn test_link_type_pcap_1() {
+    let file = File::open("fixtures/application/sample1.pcap").unwrap();
+    let report = Detector::new().report(file).unwrap();
+    assert_eq!(report.byte_order(), Some(ByteOrder::LittleEndian));
+    assert_eq!(report.link_type(), Some(1));
+}
+
+#[test]
+fn test_link_type_pcap_2() {
+    let file = File::open("fixtures/application/sample2.pcap").unwrap();
+    let report = Detector::new().report(file).unwrap();
+    assert_eq!(report.byte_order(), Some(ByteOrder::BigEndian));
+    assert_eq!(report.link_type(), Some(113));
+}
+
+#[test]
+fn test_link_type_pcapng_1() {
+    let file = File::open("fixtures/application/sample1.pcapng").unwrap();
+    let report = Detector::new().report(file).unwrap();
+    assert_eq!(report.byte_order(), Some(ByteOrder::LittleEndian));
+    assert_eq!(report.link_type(), Some(1));
+}
+
+#[test]
+fn test_link_type_pcapng_2() {
+    let file = File::open("fixtures/application/sample2.pcapng").unwrap();
+    let report = Detector::new().report(file).unwrap();
+    assert_eq!(report.byte_order(), Some(ByteOrder::BigEndian));
+    assert_eq!(report.link_type(), Some(113));
+}
+
+#[test]
+fn test_link_type_unknown() {
+    let file = File::open("fixtures/image/sample.png").unwrap();
+    let report = Detector::new().report(file).unwrap();
+    assert_eq!(report.byte_order(), None);
+    assert_eq!(report.link_type(), None);
+}
+
+#[test]
+fn test_variable_collection() {
+    let file = File::open("fixtures/font/sample.ttc").unwrap();
+    let report = Detector::new().report(file).unwrap();
+    assert!(report.variable());
+}
+
+#[test]
+fn test_variable_static() {
+    let file = File::open("fixtures/font/sample1.ttf").unwrap();
+    let report = Detector::new().report(file).unwrap();
+    assert!(!report.variable());
+}
+
+#[test]
+fn test_variable_truetype() {
+    let file = File::open("fixtures/font/sample2.ttf").unwrap();
+    let report = Detector::new().report(file).unwrap();
+    assert!(report.variable());
+}
+
+#[cfg(feature = "reader-xml")]
+#[test]
+fn test_version_gpx() {
+    let file = File::open("fixtures/geospatial/sample2.gpx").unwrap();
+    let report = Detector::new().report(file).unwrap();
+    assert_eq!(report.version(), Some("1.1"));
+}
+
+#[cfg(feature = "reader-xml")]
+#[test]
+fn test_version_kml() {
+    let file = File::open("fixtures/geospatial/sample2.kml").unwrap();
+    let report = Detector::new().report(file).unwrap();
+    assert_eq!(report.version(), Some("2.1"));
+}
+
+#[cfg(feature = "reader-xml")]
+#[test]
+fn test_version_tcx() {
+    let file = File::open("fixtures/geospatial/sample2.tcx").unwrap();
+    let report = Detector::new().report(file).unwrap();
+    assert_eq!(report.version(), Some("2"));
+    assert_eq!(report.activity(), Some("Running"));
+}
+
+#[test]
+fn test_version_unknown() {
+    let file = File::open("fixtures/image/sample.png").unwrap();
+    let report = Detector::new().report(file).unwrap();
+    assert_eq!(report.version(), None);
+    assert_eq!(report.activity(), None);
+}
+
+#[test]
+fn test_capacity_reused() {
+    let mut detector = Detector::new();
+    for _ in 0..2 {
+        let file = File::open("fixtures/disk/sample.iso").unwrap();
+        assert_eq!(detector.detect(file).unwrap(), FileFormat::Iso9660);
+        let fmt = detector.detect(Cursor::new(b"\x89PNG\r\n\x1A\n")).unwrap();
+        assert_eq!(fmt, FileFormat::PortableNetworkGraphics);
+    }
+}
+
+#[test]
+fn test_capacity_small() {
+    let mut detector = Detector::with_capacity(4096);
+    let file = File::open("fixtures/disk/sample.iso").unwrap();
+    assert_ne!(detector.detect(file).unwrap(), FileFormat::Iso9660);
+    let fmt = detector.detect(Cursor::new(b"\x89PNG\r\n\x1A\n")).unwrap();
+    assert_eq!(fmt, FileFormat::PortableNetworkGraphics);
+}
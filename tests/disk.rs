@@ -12,12 +12,30 @@ fn test_apple_disk_image() {
     assert_eq!(fmt, FileFormat::AppleDiskImage);
 }
 
+#[test]
+fn test_clonecd_control() {
+    let fmt = FileFormat::from_file("fixtures/disk/sample.ccd").unwrap();
+    assert_eq!(fmt, FileFormat::ClonecdControl);
+}
+
+#[test]
+fn test_compressed_hunks_of_data() {
+    let fmt = FileFormat::from_file("fixtures/disk/sample.chd").unwrap();
+    assert_eq!(fmt, FileFormat::CompressedHunksOfData);
+}
+
 #[test]
 fn test_iso9660() {
     let fmt = FileFormat::from_file("fixtures/disk/sample.iso").unwrap();
     assert_eq!(fmt, FileFormat::Iso9660);
 }
 
+#[test]
+fn test_media_descriptor_sidecar() {
+    let fmt = FileFormat::from_file("fixtures/disk/sample.mds").unwrap();
+    assert_eq!(fmt, FileFormat::MediaDescriptorSidecar);
+}
+
 #[test]
 fn test_microsoft_virtual_hard_disk() {
     let fmt = FileFormat::from_file("fixtures/disk/sample.vhd").unwrap();
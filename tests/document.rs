@@ -52,6 +52,20 @@ fn test_drawio_2() {
     assert_eq!(fmt, FileFormat::Drawio);
 }
 
+#[cfg(feature = "reader-cfb")]
+#[test]
+fn test_encrypted_office_open_xml() {
+    let fmt = FileFormat::from_file("fixtures/document/sample2.docx").unwrap();
+    assert_eq!(fmt, FileFormat::EncryptedOfficeOpenXml);
+}
+
+#[cfg(feature = "reader-cfb")]
+#[test]
+fn test_hangul_word_processor() {
+    let fmt = FileFormat::from_file("fixtures/document/sample.hwp").unwrap();
+    assert_eq!(fmt, FileFormat::HangulWordProcessor);
+}
+
 #[cfg(feature = "reader-zip")]
 #[test]
 fn test_indesign_markup_language() {
@@ -96,8 +110,15 @@ fn test_microsoft_visio_drawing() {
 
 #[cfg(feature = "reader-cfb")]
 #[test]
-fn test_microsoft_word_document() {
-    let fmt = FileFormat::from_file("fixtures/document/sample.doc").unwrap();
+fn test_microsoft_word_document_1() {
+    let fmt = FileFormat::from_file("fixtures/document/sample1.doc").unwrap();
+    assert_eq!(fmt, FileFormat::MicrosoftWordDocument);
+}
+
+#[cfg(feature = "reader-cfb")]
+#[test]
+fn test_microsoft_word_document_2() {
+    let fmt = FileFormat::from_file("fixtures/document/sample2.doc").unwrap();
     assert_eq!(fmt, FileFormat::MicrosoftWordDocument);
 }
 
@@ -127,6 +148,13 @@ fn test_microsoft_works_word_processor_2() {
     assert_eq!(fmt, FileFormat::MicrosoftWorksWordProcessor);
 }
 
+#[cfg(feature = "reader-cfb")]
+#[test]
+fn test_microsoft_works_word_processor_3() {
+    let fmt = FileFormat::from_file("fixtures/document/sample3.wps").unwrap();
+    assert_eq!(fmt, FileFormat::MicrosoftWorksWordProcessor);
+}
+
 #[test]
 fn test_microsoft_write() {
     let fmt = FileFormat::from_file("fixtures/document/sample.wri").unwrap();
@@ -136,7 +164,7 @@ fn test_microsoft_write() {
 #[cfg(feature = "reader-zip")]
 #[test]
 fn test_office_open_xml_document() {
-    let fmt = FileFormat::from_file("fixtures/document/sample.docx").unwrap();
+    let fmt = FileFormat::from_file("fixtures/document/sample1.docx").unwrap();
     assert_eq!(fmt, FileFormat::OfficeOpenXmlDocument);
 }
 
@@ -219,8 +247,29 @@ fn test_opendocument_spreadsheet_template() {
 
 #[cfg(feature = "reader-zip")]
 #[test]
-fn test_opendocument_text() {
-    let fmt = FileFormat::from_file("fixtures/document/sample.odt").unwrap();
+fn test_opendocument_text_1() {
+    let fmt = FileFormat::from_file("fixtures/document/sample1.odt").unwrap();
+    assert_eq!(fmt, FileFormat::OpendocumentText);
+}
+
+#[cfg(feature = "reader-zip")]
+#[test]
+fn test_opendocument_text_2() {
+    let fmt = FileFormat::from_file("fixtures/document/sample2.odt").unwrap();
+    assert_eq!(fmt, FileFormat::OpendocumentText);
+}
+
+#[cfg(feature = "reader-zip")]
+#[test]
+fn test_opendocument_text_3() {
+    let fmt = FileFormat::from_file("fixtures/document/sample3.odt").unwrap();
+    assert_eq!(fmt, FileFormat::OpendocumentText);
+}
+
+#[cfg(feature = "reader-zip")]
+#[test]
+fn test_opendocument_text_4() {
+    let fmt = FileFormat::from_file("fixtures/document/sample4.odt").unwrap();
     assert_eq!(fmt, FileFormat::OpendocumentText);
 }
 
@@ -1,5 +1,11 @@
 use file_format::FileFormat;
 
+#[test]
+fn test_appimage() {
+    let fmt = FileFormat::from_file("fixtures/executable/sample.appimage").unwrap();
+    assert_eq!(fmt, FileFormat::Appimage);
+}
+
 #[test]
 fn test_common_object_file_format() {
     let fmt = FileFormat::from_file("fixtures/executable/sample.coff").unwrap();
@@ -12,6 +18,19 @@ fn test_dalvik_executable() {
     assert_eq!(fmt, FileFormat::DalvikExecutable);
 }
 
+#[cfg(feature = "reader-dxbc")]
+#[test]
+fn test_directx_intermediate_language() {
+    let fmt = FileFormat::from_file("fixtures/executable/sample2.cso").unwrap();
+    assert_eq!(fmt, FileFormat::DirectxIntermediateLanguage);
+}
+
+#[test]
+fn test_directx_shader_bytecode() {
+    let fmt = FileFormat::from_file("fixtures/executable/sample1.cso").unwrap();
+    assert_eq!(fmt, FileFormat::DirectxShaderBytecode);
+}
+
 #[cfg(feature = "reader-exe")]
 #[test]
 fn test_dynamic_link_library() {
@@ -75,6 +94,26 @@ fn test_nintendo_switch_executable() {
     assert_eq!(fmt, FileFormat::NintendoSwitchExecutable);
 }
 
+#[cfg(feature = "reader-exe")]
+#[test]
+fn test_nullsoft_scriptable_install_system() {
+    let fmt = FileFormat::from_file("fixtures/executable/sample6.exe").unwrap();
+    assert_eq!(fmt, FileFormat::NullsoftScriptableInstallSystem);
+}
+
+#[cfg(feature = "reader-exe")]
+#[test]
+fn test_nullsoft_scriptable_install_system_section() {
+    // Adds a section holding the NSIS header, which is not an overlay.
+    let mut bytes = std::fs::read("fixtures/executable/sample6.exe").unwrap();
+    bytes[70] = 1;
+    bytes[104..112].copy_from_slice(b"\0\x02\0\0\0\x02\0\0");
+    assert_eq!(
+        FileFormat::from_bytes(&bytes),
+        FileFormat::PortableExecutable
+    );
+}
+
 #[test]
 fn test_optimized_dalvik_executable() {
     let fmt = FileFormat::from_file("fixtures/executable/sample.dey").unwrap();
@@ -88,12 +127,24 @@ fn test_portable_executable() {
     assert_eq!(fmt, FileFormat::PortableExecutable);
 }
 
+#[test]
+fn test_spir_v() {
+    let fmt = FileFormat::from_file("fixtures/executable/sample.spv").unwrap();
+    assert_eq!(fmt, FileFormat::SpirV);
+}
+
 #[test]
 fn test_webassembly_binary() {
-    let fmt = FileFormat::from_file("fixtures/executable/sample.wasm").unwrap();
+    let fmt = FileFormat::from_file("fixtures/executable/sample1.wasm").unwrap();
     assert_eq!(fmt, FileFormat::WebassemblyBinary);
 }
 
+#[test]
+fn test_webassembly_component() {
+    let fmt = FileFormat::from_file("fixtures/executable/sample2.wasm").unwrap();
+    assert_eq!(fmt, FileFormat::WebassemblyComponent);
+}
+
 #[test]
 fn test_xbox360_executable() {
     let fmt = FileFormat::from_file("fixtures/executable/sample.xex").unwrap();
@@ -0,0 +1,52 @@
+#![cfg(feature = "ffi")]
+
+use file_format::{ffi::*, FileFormat};
+use std::{ffi::CStr, ptr};
+
+#[test]
+fn test_from_bytes() {
+    let bytes = b"\x89\x50\x4E\x47\x0D\x0A\x1A\x0A";
+    let id = unsafe { file_format_from_bytes(bytes.as_ptr(), bytes.len()) };
+    assert_eq!(id, FileFormat::PortableNetworkGraphics.id());
+}
+
+#[test]
+fn test_from_bytes_empty() {
+    let bytes = b"";
+    let id = unsafe { file_format_from_bytes(bytes.as_ptr(), 0) };
+    assert_eq!(id, FileFormat::Empty.id());
+}
+
+#[test]
+fn test_from_bytes_null() {
+    let id = unsafe { file_format_from_bytes(ptr::null(), 16) };
+    assert_eq!(id, FileFormat::Empty.id());
+}
+
+#[test]
+fn test_name() {
+    let name = file_format_name(FileFormat::PortableNetworkGraphics.id());
+    let name = unsafe { CStr::from_ptr(name) };
+    assert_eq!(name.to_str(), Ok("Portable Network Graphics"));
+}
+
+#[test]
+fn test_media_type() {
+    let media_type = file_format_media_type(FileFormat::PortableNetworkGraphics.id());
+    let media_type = unsafe { CStr::from_ptr(media_type) };
+    assert_eq!(media_type.to_str(), Ok("image/png"));
+}
+
+#[test]
+fn test_extension() {
+    let extension = file_format_extension(FileFormat::PortableNetworkGraphics.id());
+    let extension = unsafe { CStr::from_ptr(extension) };
+    assert_eq!(extension.to_str(), Ok("png"));
+}
+
+#[test]
+fn test_unknown_id() {
+    assert!(file_format_name(0).is_null());
+    assert!(file_format_media_type(0).is_null());
+    assert!(file_format_extension(0).is_null());
+}
@@ -1,5 +1,19 @@
 use file_format::FileFormat;
 
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_adobe_font_metrics() {
+    let fmt = FileFormat::from_file("fixtures/font/sample.afm").unwrap();
+    assert_eq!(fmt, FileFormat::AdobeFontMetrics);
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_adobe_font_metrics_invalid() {
+    let fmt = FileFormat::from_bytes(b"StartFontMetrics are described below.\n");
+    assert_ne!(fmt, FileFormat::AdobeFontMetrics);
+}
+
 #[test]
 fn test_bitmap_font_ascii() {
     let fmt = FileFormat::from_file("fixtures/font/sample1.fnt").unwrap();
@@ -18,6 +32,12 @@ fn test_embedded_opentype() {
     assert_eq!(fmt, FileFormat::EmbeddedOpentype);
 }
 
+#[test]
+fn test_glyph_bitmap_distribution_format() {
+    let fmt = FileFormat::from_file("fixtures/font/sample.bdf").unwrap();
+    assert_eq!(fmt, FileFormat::GlyphBitmapDistributionFormat);
+}
+
 #[test]
 fn test_opentype() {
     let fmt = FileFormat::from_file("fixtures/font/sample.otf").unwrap();
@@ -25,11 +45,41 @@ fn test_opentype() {
 }
 
 #[test]
-fn test_truetype() {
-    let fmt = FileFormat::from_file("fixtures/font/sample.ttf").unwrap();
+fn test_portable_compiled_format() {
+    let fmt = FileFormat::from_file("fixtures/font/sample.pcf").unwrap();
+    assert_eq!(fmt, FileFormat::PortableCompiledFormat);
+}
+
+#[test]
+fn test_printer_font_ascii() {
+    let fmt = FileFormat::from_file("fixtures/font/sample.pfa").unwrap();
+    assert_eq!(fmt, FileFormat::PrinterFontAscii);
+}
+
+#[test]
+fn test_printer_font_binary() {
+    let fmt = FileFormat::from_file("fixtures/font/sample.pfb").unwrap();
+    assert_eq!(fmt, FileFormat::PrinterFontBinary);
+}
+
+#[test]
+fn test_truetype_1() {
+    let fmt = FileFormat::from_file("fixtures/font/sample1.ttf").unwrap();
+    assert_eq!(fmt, FileFormat::Truetype);
+}
+
+#[test]
+fn test_truetype_2() {
+    let fmt = FileFormat::from_file("fixtures/font/sample2.ttf").unwrap();
     assert_eq!(fmt, FileFormat::Truetype);
 }
 
+#[test]
+fn test_truetype_collection() {
+    let fmt = FileFormat::from_file("fixtures/font/sample.ttc").unwrap();
+    assert_eq!(fmt, FileFormat::TruetypeCollection);
+}
+
 #[test]
 fn test_web_open_font_format() {
     let fmt = FileFormat::from_file("fixtures/font/sample.woff").unwrap();
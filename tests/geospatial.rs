@@ -1,5 +1,19 @@
 use file_format::FileFormat;
 
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_esri_ascii_grid() {
+    let fmt = FileFormat::from_file("fixtures/geospatial/sample.asc").unwrap();
+    assert_eq!(fmt, FileFormat::EsriAsciiGrid);
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_esri_ascii_grid_invalid() {
+    let fmt = FileFormat::from_bytes(b"ncols 4\nnrows 3\nxllcorner 2.3\ncellsize 0.025\n");
+    assert_ne!(fmt, FileFormat::EsriAsciiGrid);
+}
+
 #[test]
 fn test_flexible_and_interoperable_data_transfer() {
     let fmt = FileFormat::from_file("fixtures/geospatial/sample.fit").unwrap();
@@ -52,12 +66,32 @@ fn test_keyhole_markup_language_zipped() {
     assert_eq!(fmt, FileFormat::KeyholeMarkupLanguageZipped);
 }
 
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_projection_wkt() {
+    let fmt = FileFormat::from_file("fixtures/geospatial/sample.prj").unwrap();
+    assert_eq!(fmt, FileFormat::ProjectionWkt);
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_projection_wkt_invalid() {
+    let fmt = FileFormat::from_bytes(b"GEOGCS is a WKT keyword.");
+    assert_ne!(fmt, FileFormat::ProjectionWkt);
+}
+
 #[test]
 fn test_shapefile() {
     let fmt = FileFormat::from_file("fixtures/geospatial/sample.shp").unwrap();
     assert_eq!(fmt, FileFormat::Shapefile);
 }
 
+#[test]
+fn test_shapefile_index() {
+    let fmt = FileFormat::from_file("fixtures/geospatial/sample.shx").unwrap();
+    assert_eq!(fmt, FileFormat::ShapefileIndex);
+}
+
 #[test]
 fn test_training_center_xml_1() {
     let fmt = FileFormat::from_file("fixtures/geospatial/sample1.tcx").unwrap();
@@ -70,3 +104,24 @@ fn test_training_center_xml_2() {
     let fmt = FileFormat::from_file("fixtures/geospatial/sample2.tcx").unwrap();
     assert_eq!(fmt, FileFormat::TrainingCenterXml);
 }
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_world_file() {
+    let fmt = FileFormat::from_file("fixtures/geospatial/sample.tfw").unwrap();
+    assert_eq!(fmt, FileFormat::WorldFile);
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_world_file_invalid() {
+    let fmt = FileFormat::from_bytes(b"1\n2\n3\n4\n5\n6\n7\n");
+    assert_ne!(fmt, FileFormat::WorldFile);
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_world_file_integers() {
+    let fmt = FileFormat::from_bytes(b"1\n2\n3\n4\n5\n6\n");
+    assert_eq!(fmt, FileFormat::PlainText);
+}
@@ -73,6 +73,12 @@ fn test_cineon() {
     assert_eq!(fmt, FileFormat::Cineon);
 }
 
+#[test]
+fn test_coreldraw() {
+    let fmt = FileFormat::from_file("fixtures/image/sample.cdr").unwrap();
+    assert_eq!(fmt, FileFormat::Coreldraw);
+}
+
 #[test]
 fn test_digital_picture_exchange() {
     let fmt = FileFormat::from_file("fixtures/image/sample.dpx").unwrap();
@@ -85,6 +91,12 @@ fn test_djvu() {
     assert_eq!(fmt, FileFormat::Djvu);
 }
 
+#[test]
+fn test_enhanced_metafile() {
+    let fmt = FileFormat::from_file("fixtures/image/sample.emf").unwrap();
+    assert_eq!(fmt, FileFormat::EnhancedMetafile);
+}
+
 #[test]
 fn test_experimental_computing_facility() {
     let fmt = FileFormat::from_file("fixtures/image/sample.xcf").unwrap();
@@ -110,8 +122,14 @@ fn test_fujifilm_raw() {
 }
 
 #[test]
-fn test_graphics_interchange_format() {
-    let fmt = FileFormat::from_file("fixtures/image/sample.gif").unwrap();
+fn test_graphics_interchange_format_1() {
+    let fmt = FileFormat::from_file("fixtures/image/sample1.gif").unwrap();
+    assert_eq!(fmt, FileFormat::GraphicsInterchangeFormat);
+}
+
+#[test]
+fn test_graphics_interchange_format_2() {
+    let fmt = FileFormat::from_file("fixtures/image/sample2.gif").unwrap();
     assert_eq!(fmt, FileFormat::GraphicsInterchangeFormat);
 }
 
@@ -138,6 +156,13 @@ fn test_high_efficiency_image_file_format_sequence() {
     assert_eq!(fmt, FileFormat::HighEfficiencyImageFileFormatSequence);
 }
 
+#[cfg(feature = "reader-iff")]
+#[test]
+fn test_interleaved_bitmap() {
+    let fmt = FileFormat::from_file("fixtures/image/sample.lbm").unwrap();
+    assert_eq!(fmt, FileFormat::InterleavedBitmap);
+}
+
 #[test]
 fn test_joint_photographic_experts_group() {
     let fmt = FileFormat::from_file("fixtures/image/sample.jpg").unwrap();
@@ -332,6 +357,20 @@ fn test_tag_image_file_format() {
     assert_eq!(fmt, FileFormat::TagImageFileFormat);
 }
 
+#[cfg(feature = "reader-tga")]
+#[test]
+fn test_truevision_tga() {
+    let fmt = FileFormat::from_file("fixtures/image/sample.tga").unwrap();
+    assert_eq!(fmt, FileFormat::TruevisionTga);
+}
+
+#[cfg(feature = "reader-tga")]
+#[test]
+fn test_truevision_tga_invalid() {
+    let fmt = FileFormat::from_bytes(b"\0\0\x04TRUEVISION-XFILE.\0");
+    assert_ne!(fmt, FileFormat::TruevisionTga);
+}
+
 #[test]
 fn test_webp() {
     let fmt = FileFormat::from_file("fixtures/image/sample.webp").unwrap();
@@ -363,8 +402,14 @@ fn test_windows_icon() {
 }
 
 #[test]
-fn test_windows_metafile() {
-    let fmt = FileFormat::from_file("fixtures/image/sample.wmf").unwrap();
+fn test_windows_metafile_1() {
+    let fmt = FileFormat::from_file("fixtures/image/sample1.wmf").unwrap();
+    assert_eq!(fmt, FileFormat::WindowsMetafile);
+}
+
+#[test]
+fn test_windows_metafile_2() {
+    let fmt = FileFormat::from_file("fixtures/image/sample2.wmf").unwrap();
     assert_eq!(fmt, FileFormat::WindowsMetafile);
 }
 
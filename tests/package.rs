@@ -14,6 +14,13 @@ fn test_android_package() {
     assert_eq!(fmt, FileFormat::AndroidPackage);
 }
 
+#[cfg(feature = "reader-zip")]
+#[test]
+fn test_conda_package() {
+    let fmt = FileFormat::from_file("fixtures/package/sample.conda").unwrap();
+    assert_eq!(fmt, FileFormat::CondaPackage);
+}
+
 #[test]
 fn test_debian_binary_package() {
     let fmt = FileFormat::from_file("fixtures/package/sample.deb").unwrap();
@@ -27,6 +34,12 @@ fn test_enterprise_application_archive() {
     assert_eq!(fmt, FileFormat::EnterpriseApplicationArchive);
 }
 
+#[test]
+fn test_godot_resource_pack() {
+    let fmt = FileFormat::from_file("fixtures/package/sample.pck").unwrap();
+    assert_eq!(fmt, FileFormat::GodotResourcePack);
+}
+
 #[test]
 fn test_google_chrome_extension() {
     let fmt = FileFormat::from_file("fixtures/package/sample.crx").unwrap();
@@ -67,12 +80,40 @@ fn test_nintendo_switch_package() {
     assert_eq!(fmt, FileFormat::NintendoSwitchPackage);
 }
 
+#[cfg(feature = "reader-zip")]
+#[test]
+fn test_python_egg() {
+    let fmt = FileFormat::from_file("fixtures/package/sample.egg").unwrap();
+    assert_eq!(fmt, FileFormat::PythonEgg);
+}
+
+#[cfg(feature = "reader-zip")]
+#[test]
+fn test_python_wheel() {
+    let fmt = FileFormat::from_file("fixtures/package/sample.whl").unwrap();
+    assert_eq!(fmt, FileFormat::PythonWheel);
+}
+
 #[test]
 fn test_red_hat_package_manager() {
     let fmt = FileFormat::from_file("fixtures/package/sample.rpm").unwrap();
     assert_eq!(fmt, FileFormat::RedHatPackageManager);
 }
 
+#[cfg(feature = "reader-tar")]
+#[test]
+fn test_ruby_gem() {
+    let fmt = FileFormat::from_file("fixtures/package/sample.gem").unwrap();
+    assert_eq!(fmt, FileFormat::RubyGem);
+}
+
+#[cfg(feature = "reader-squashfs")]
+#[test]
+fn test_snap() {
+    let fmt = FileFormat::from_file("fixtures/package/sample.snap").unwrap();
+    assert_eq!(fmt, FileFormat::Snap);
+}
+
 #[cfg(feature = "reader-zip")]
 #[test]
 fn test_web_application_archive() {
@@ -6,6 +6,12 @@ fn test_atari7800_rom() {
     assert_eq!(fmt, FileFormat::Atari7800Rom);
 }
 
+#[test]
+fn test_compressed_iso() {
+    let fmt = FileFormat::from_file("fixtures/rom/sample.cso").unwrap();
+    assert_eq!(fmt, FileFormat::CompressedIso);
+}
+
 #[test]
 fn test_game_boy_advance_rom() {
     let fmt = FileFormat::from_file("fixtures/rom/sample.gba").unwrap();
@@ -18,18 +24,54 @@ fn test_game_boy_color_rom() {
     assert_eq!(fmt, FileFormat::GameBoyColorRom);
 }
 
+#[test]
+fn test_game_boy_color_rom_header() {
+    let fmt = FileFormat::from_file("fixtures/rom/sample2.gbc").unwrap();
+    assert_eq!(fmt, FileFormat::GameBoyColorRom);
+}
+
 #[test]
 fn test_game_boy_rom() {
     let fmt = FileFormat::from_file("fixtures/rom/sample.gb").unwrap();
     assert_eq!(fmt, FileFormat::GameBoyRom);
 }
 
+#[test]
+fn test_game_boy_rom_header() {
+    let fmt = FileFormat::from_file("fixtures/rom/sample2.gb").unwrap();
+    assert_eq!(fmt, FileFormat::GameBoyRom);
+}
+
+#[cfg(feature = "reader-gb")]
+#[test]
+fn test_game_boy_rom_invalid_checksum() {
+    let mut data = std::fs::read("fixtures/rom/sample2.gb").unwrap();
+    data[0x14D] ^= 0xFF;
+    let fmt = FileFormat::from_bytes(&data);
+    assert_ne!(fmt, FileFormat::GameBoyRom);
+}
+
+#[cfg(feature = "reader-gb")]
+#[test]
+fn test_game_boy_rom_invalid_logo() {
+    let mut data = std::fs::read("fixtures/rom/sample2.gb").unwrap();
+    data[0x120] ^= 0xFF;
+    let fmt = FileFormat::from_bytes(&data);
+    assert_ne!(fmt, FileFormat::GameBoyRom);
+}
+
 #[test]
 fn test_game_gear_rom() {
     let fmt = FileFormat::from_file("fixtures/rom/sample.gg").unwrap();
     assert_eq!(fmt, FileFormat::GameGearRom);
 }
 
+#[test]
+fn test_gamecube_disc() {
+    let fmt = FileFormat::from_file("fixtures/rom/sample.gcm").unwrap();
+    assert_eq!(fmt, FileFormat::GamecubeDisc);
+}
+
 #[test]
 fn test_mega_drive_rom() {
     let fmt = FileFormat::from_file("fixtures/rom/sample.md").unwrap();
@@ -48,6 +90,12 @@ fn test_neo_geo_pocket_rom() {
     assert_eq!(fmt, FileFormat::NeoGeoPocketRom);
 }
 
+#[test]
+fn test_nintendo3ds_rom() {
+    let fmt = FileFormat::from_file("fixtures/rom/sample.3ds").unwrap();
+    assert_eq!(fmt, FileFormat::Nintendo3dsRom);
+}
+
 #[test]
 fn test_nintendo64_rom() {
     let fmt = FileFormat::from_file("fixtures/rom/sample.z64").unwrap();
@@ -72,8 +120,50 @@ fn test_nintendo_switch_rom() {
     assert_eq!(fmt, FileFormat::NintendoSwitchRom);
 }
 
+#[test]
+fn test_playstation_portable_iso() {
+    let fmt = FileFormat::from_file("fixtures/rom/sample2.iso").unwrap();
+    assert_eq!(fmt, FileFormat::PlaystationPortableIso);
+}
+
+#[test]
+fn test_sega_genesis_rom() {
+    let fmt = FileFormat::from_file("fixtures/rom/sample.gen").unwrap();
+    assert_eq!(fmt, FileFormat::SegaGenesisRom);
+}
+
+#[test]
+fn test_sega_genesis_rom_invalid() {
+    let mut data = std::fs::read("fixtures/rom/sample.gen").unwrap();
+    data.copy_within(0x101..0x110, 0x100);
+    let fmt = FileFormat::from_bytes(&data);
+    assert_ne!(fmt, FileFormat::SegaGenesisRom);
+}
+
 #[test]
 fn test_sega_master_system_rom() {
     let fmt = FileFormat::from_file("fixtures/rom/sample.sms").unwrap();
     assert_eq!(fmt, FileFormat::SegaMasterSystemRom);
 }
+
+#[cfg(feature = "reader-sfc")]
+#[test]
+fn test_super_nintendo_rom() {
+    let fmt = FileFormat::from_file("fixtures/rom/sample.sfc").unwrap();
+    assert_eq!(fmt, FileFormat::SuperNintendoRom);
+}
+
+#[cfg(feature = "reader-sfc")]
+#[test]
+fn test_super_nintendo_rom_invalid() {
+    let mut data = std::fs::read("fixtures/rom/sample.sfc").unwrap();
+    data[0x7FDC] ^= 0xFF;
+    let fmt = FileFormat::from_bytes(&data);
+    assert_ne!(fmt, FileFormat::SuperNintendoRom);
+}
+
+#[test]
+fn test_wii_disc() {
+    let fmt = FileFormat::from_file("fixtures/rom/sample1.iso").unwrap();
+    assert_eq!(fmt, FileFormat::WiiDisc);
+}
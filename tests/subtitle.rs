@@ -14,12 +14,32 @@ fn test_mpeg4_part14_subtitles() {
     assert_eq!(fmt, FileFormat::Mpeg4Part14Subtitles);
 }
 
+#[test]
+fn test_presentation_graphic_stream() {
+    let fmt = FileFormat::from_file("fixtures/subtitle/sample.sup").unwrap();
+    assert_eq!(fmt, FileFormat::PresentationGraphicStream);
+}
+
 #[test]
 fn test_subrip_text() {
     let fmt = FileFormat::from_file("fixtures/subtitle/sample.srt").unwrap();
     assert_eq!(fmt, FileFormat::SubripText);
 }
 
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_synchronized_accessible_media_interchange() {
+    let fmt = FileFormat::from_file("fixtures/subtitle/sample.smi").unwrap();
+    assert_eq!(fmt, FileFormat::SynchronizedAccessibleMediaInterchange);
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_synchronized_accessible_media_interchange_invalid() {
+    let fmt = FileFormat::from_bytes(b"<samizdat>\n<sync>\n");
+    assert_ne!(fmt, FileFormat::SynchronizedAccessibleMediaInterchange);
+}
+
 #[test]
 fn test_timed_text_markup_language_1() {
     let fmt = FileFormat::from_file("fixtures/subtitle/sample1.ttml").unwrap();
@@ -46,6 +66,12 @@ fn test_universal_subtitle_format_2() {
     assert_eq!(fmt, FileFormat::UniversalSubtitleFormat);
 }
 
+#[test]
+fn test_vobsub_index() {
+    let fmt = FileFormat::from_file("fixtures/subtitle/sample.idx").unwrap();
+    assert_eq!(fmt, FileFormat::VobsubIndex);
+}
+
 #[test]
 fn test_web_video_text_tracks() {
     let fmt = FileFormat::from_file("fixtures/subtitle/sample.vtt").unwrap();
@@ -1,17 +1,68 @@
 use file_format::FileFormat;
 
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_base64_1() {
+    let fmt = FileFormat::from_file("fixtures/text/sample1.b64").unwrap();
+    assert_eq!(fmt, FileFormat::Base64);
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_base64_2() {
+    let fmt = FileFormat::from_file("fixtures/text/sample2.b64").unwrap();
+    assert_eq!(fmt, FileFormat::Base64);
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_base64_hexadecimal() {
+    let fmt = FileFormat::from_bytes(&b"0123456789abcdef".repeat(8));
+    assert_ne!(fmt, FileFormat::Base64);
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_base64_prose() {
+    let fmt = FileFormat::from_bytes(
+        b"Base64 is a group of binary-to-text encoding schemes, see RFC 4648.",
+    );
+    assert_ne!(fmt, FileFormat::Base64);
+}
+
 #[test]
 fn test_clojure_script() {
     let fmt = FileFormat::from_file("fixtures/text/sample.clj").unwrap();
     assert_eq!(fmt, FileFormat::ClojureScript);
 }
 
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_context_diff() {
+    let fmt = FileFormat::from_file("fixtures/text/sample2.diff").unwrap();
+    assert_eq!(fmt, FileFormat::ContextDiff);
+}
+
 #[test]
 fn test_extensible_markup_language() {
     let fmt = FileFormat::from_file("fixtures/text/sample.xml").unwrap();
     assert_eq!(fmt, FileFormat::ExtensibleMarkupLanguage);
 }
 
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_graphviz_dot() {
+    let fmt = FileFormat::from_file("fixtures/text/sample.gv").unwrap();
+    assert_eq!(fmt, FileFormat::GraphvizDot);
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_graphviz_dot_prose() {
+    let fmt = FileFormat::from_bytes(b"Graph of the sales {see the table below} -> up [sic].");
+    assert_eq!(fmt, FileFormat::PlainText);
+}
+
 #[test]
 fn test_hypertext_markup_language() {
     let fmt = FileFormat::from_file("fixtures/text/sample.html").unwrap();
@@ -50,8 +101,22 @@ fn test_perl_script() {
 
 #[cfg(feature = "reader-txt")]
 #[test]
-fn test_plain_text() {
-    let fmt = FileFormat::from_file("fixtures/text/sample.txt").unwrap();
+fn test_plain_text_1() {
+    let fmt = FileFormat::from_file("fixtures/text/sample1.txt").unwrap();
+    assert_eq!(fmt, FileFormat::PlainText);
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_plain_text_2() {
+    let fmt = FileFormat::from_file("fixtures/text/sample2.txt").unwrap();
+    assert_eq!(fmt, FileFormat::PlainText);
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_plain_text_3() {
+    let fmt = FileFormat::from_file("fixtures/text/sample3.txt").unwrap();
     assert_eq!(fmt, FileFormat::PlainText);
 }
 
@@ -73,12 +138,55 @@ fn test_shell_script() {
     assert_eq!(fmt, FileFormat::ShellScript);
 }
 
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_sql_script_1() {
+    let fmt = FileFormat::from_file("fixtures/text/sample1.sql").unwrap();
+    assert_eq!(fmt, FileFormat::SqlScript);
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_sql_script_2() {
+    let fmt = FileFormat::from_file("fixtures/text/sample2.sql").unwrap();
+    assert_eq!(fmt, FileFormat::SqlScript);
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_sql_script_3() {
+    let fmt = FileFormat::from_file("fixtures/text/sample3.sql").unwrap();
+    assert_eq!(fmt, FileFormat::SqlScript);
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_sql_script_prose() {
+    let fmt =
+        FileFormat::from_bytes(b"Create table settings first; then insert into the list; done.");
+    assert_eq!(fmt, FileFormat::PlainText);
+}
+
 #[test]
 fn test_tool_command_language_script() {
     let fmt = FileFormat::from_file("fixtures/text/sample.tcl").unwrap();
     assert_eq!(fmt, FileFormat::ToolCommandLanguageScript);
 }
 
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_unified_diff_1() {
+    let fmt = FileFormat::from_file("fixtures/text/sample1.diff").unwrap();
+    assert_eq!(fmt, FileFormat::UnifiedDiff);
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_unified_diff_2() {
+    let fmt = FileFormat::from_file("fixtures/text/sample.patch").unwrap();
+    assert_eq!(fmt, FileFormat::UnifiedDiff);
+}
+
 #[test]
 fn test_vcalendar() {
     let fmt = FileFormat::from_file("fixtures/text/sample.vcs").unwrap();
@@ -96,3 +204,17 @@ fn test_webassembly_text() {
     let fmt = FileFormat::from_file("fixtures/text/sample.wat").unwrap();
     assert_eq!(fmt, FileFormat::WebassemblyText);
 }
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_zeek_log() {
+    let fmt = FileFormat::from_file("fixtures/text/sample.log").unwrap();
+    assert_eq!(fmt, FileFormat::ZeekLog);
+}
+
+#[cfg(feature = "reader-txt")]
+#[test]
+fn test_zeek_log_invalid() {
+    let fmt = FileFormat::from_bytes(b"#separator line\nno fields follow\n");
+    assert_ne!(fmt, FileFormat::ZeekLog);
+}
@@ -60,6 +60,13 @@ fn test_flash_video() {
     assert_eq!(fmt, FileFormat::FlashVideo);
 }
 
+#[cfg(feature = "reader-iff")]
+#[test]
+fn test_iff_animation() {
+    let fmt = FileFormat::from_file("fixtures/video/sample.anim").unwrap();
+    assert_eq!(fmt, FileFormat::IffAnimation);
+}
+
 #[test]
 fn test_jpeg2000_part3() {
     let fmt = FileFormat::from_file("fixtures/video/sample.mj2").unwrap();
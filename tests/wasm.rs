@@ -0,0 +1,17 @@
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use file_format::wasm::detect;
+use js_sys::Reflect;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn test_detect() {
+    let object = detect(b"\x89\x50\x4E\x47\x0D\x0A\x1A\x0A");
+    let property = |key| Reflect::get(&object, &JsValue::from_str(key)).unwrap();
+    assert_eq!(property("name"), "Portable Network Graphics");
+    assert_eq!(property("shortName"), "PNG");
+    assert_eq!(property("mediaType"), "image/png");
+    assert_eq!(property("extension"), "png");
+    assert_eq!(property("kind"), "Image");
+}